@@ -28,6 +28,7 @@ async fn main() {
         "upgrade" => interact.upgrade().await,
         "buyTicket" => interact.buy().await,
         "issueFreeTicket" => interact.giveaway().await,
+        "issueFreeStageTicket" => interact.giveaway_stage().await,
         "issuePaidTicket" => interact.giveaway_admin().await,
         "setFees" => interact.set_cut_fees().await,
         "getAllEvents" => interact.events().await,
@@ -204,6 +205,31 @@ impl ContractInteract {
     async fn giveaway(&mut self) {
         let event_id = ManagedBuffer::new_from_bytes(&b""[..]);
         let ticket_type_id = ManagedBuffer::new_from_bytes(&b""[..]);
+        let external_id = ManagedBuffer::new_from_bytes(&b""[..]);
+        let users = MultiValueVec::from(vec![MultiValue2::<ManagedAddress<StaticApi>, u32>::from((bech32::decode(""), 0u32))]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::TicketingProxy)
+            .giveaway(event_id, ticket_type_id, external_id, users)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn giveaway_stage(&mut self) {
+        let event_id = ManagedBuffer::new_from_bytes(&b""[..]);
+        let ticket_type_id = ManagedBuffer::new_from_bytes(&b""[..]);
+        let ticket_stage_id = ManagedBuffer::new_from_bytes(&b""[..]);
+        let respect_limits = false;
+        let external_id = ManagedBuffer::new_from_bytes(&b""[..]);
         let users = MultiValueVec::from(vec![MultiValue2::<ManagedAddress<StaticApi>, u32>::from((bech32::decode(""), 0u32))]);
 
         let response = self
@@ -213,7 +239,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::TicketingProxy)
-            .giveaway(event_id, ticket_type_id, users)
+            .giveaway_stage(event_id, ticket_type_id, ticket_stage_id, respect_limits, external_id, users)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -45,14 +45,17 @@ where
 {
     pub fn init<
         Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<MultiValue3<ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>>>>,
     >(
         self,
         fees: Arg0,
+        standalone: Arg1,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_deploy()
             .argument(&fees)
+            .argument(&standalone)
             .original_result()
     }
 }
@@ -118,6 +121,100 @@ where
             .original_result()
     }
 
+    pub fn buy_with_referral<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg6: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg7: ProxyArg<OptionalValue<ManagedVec<Env::Api, AggregatorStep<Env::Api>>>>,
+        Arg8: ProxyArg<OptionalValue<ManagedVec<Env::Api, TokenAmount<Env::Api>>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        quantity: Arg3,
+        referral_code: Arg4,
+        signature: Arg5,
+        data: Arg6,
+        swaps: Arg7,
+        limits: Arg8,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("buyTicketWithReferral")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&quantity)
+            .argument(&referral_code)
+            .argument(&signature)
+            .argument(&data)
+            .argument(&swaps)
+            .argument(&limits)
+            .original_result()
+    }
+
+    pub fn buy_with_receipt<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg6: ProxyArg<BigUint<Env::Api>>,
+        Arg7: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg8: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg9: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg10: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg11: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        quantity: Arg3,
+        to: Arg4,
+        order_id: Arg5,
+        amount: Arg6,
+        currency: Arg7,
+        provider: Arg8,
+        receipt_signature: Arg9,
+        signature: Arg10,
+        data: Arg11,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("buyWithReceipt")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&quantity)
+            .argument(&to)
+            .argument(&order_id)
+            .argument(&amount)
+            .argument(&currency)
+            .argument(&provider)
+            .argument(&receipt_signature)
+            .argument(&signature)
+            .argument(&data)
+            .original_result()
+    }
+
+    pub fn refund<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("refundTicket")
+            .argument(&event_id)
+            .original_result()
+    }
+
     pub fn giveaway<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -140,6 +237,34 @@ where
             .original_result()
     }
 
+    pub fn giveaway_stage<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<bool>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        respect_limits: Arg3,
+        external_id: Arg4,
+        users: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("issueFreeStageTicket")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&respect_limits)
+            .argument(&external_id)
+            .argument(&users)
+            .original_result()
+    }
+
     pub fn giveaway_admin<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -190,6 +315,103 @@ where
             .original_result()
     }
 
+    pub fn enable_metadata_updates<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableMetadataUpdates")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn flush_platform_fees<
+        Arg0: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flushPlatformFees")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn release_refund_escrow_endpoint<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        batch_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseRefundEscrow")
+            .argument(&event_id)
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn refresh_manager_config<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        signer: Arg0,
+        aggregator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refreshManagerConfig")
+            .argument(&signer)
+            .argument(&aggregator)
+            .original_result()
+    }
+
+    pub fn add_payment_provider<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        provider: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addPaymentProvider")
+            .argument(&provider)
+            .original_result()
+    }
+
+    pub fn remove_payment_provider<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        provider: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removePaymentProvider")
+            .argument(&provider)
+            .original_result()
+    }
+
+    pub fn set_fee_recipient<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeeRecipient")
+            .argument(&address)
+            .original_result()
+    }
+
     pub fn set_cut_fees<
         Arg0: ProxyArg<BigUint<Env::Api>>,
     >(
@@ -203,6 +425,22 @@ where
             .original_result()
     }
 
+    pub fn set_event_fees<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        fees: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEventFees")
+            .argument(&event_id)
+            .argument(&fees)
+            .original_result()
+    }
+
     pub fn events(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
@@ -381,173 +619,899 @@ where
             .original_result()
     }
 
-    pub fn transfer_wallets<
+    pub fn ticket_type_nonces<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        ticket_type_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTransferWallets")
+            .raw_call("getTicketTypeNonces")
             .argument(&event_id)
+            .argument(&ticket_type_id)
             .original_result()
     }
 
-    pub fn fees(
-        self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("getFees")
-            .original_result()
-    }
-
-    pub fn income(
+    pub fn comped_tickets<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getIncome")
+            .raw_call("getCompedTickets")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
             .original_result()
     }
 
-    pub fn is_whitelisted<
+    pub fn metadata_cursor<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         event_id: Arg0,
         ticket_type_id: Arg1,
-        ticket_stage_id: Arg2,
-        address: Arg3,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isWhitelisted")
+            .raw_call("getMetadataCursor")
             .argument(&event_id)
             .argument(&ticket_type_id)
-            .argument(&ticket_stage_id)
-            .argument(&address)
             .original_result()
     }
 
-    pub fn get_events(
+    pub fn metadata_update_state<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, Event<Env::Api>>> {
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MetadataUpdateState> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getEvents")
+            .raw_call("getMetadataUpdateState")
+            .argument(&event_id)
             .original_result()
     }
 
-    pub fn get_types<
+    pub fn transfer_wallets<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketType<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTypes")
+            .raw_call("getTransferWallets")
             .argument(&event_id)
             .original_result()
     }
 
-    pub fn get_type_stages<
+    pub fn event_buyers<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-        ticket_type_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketStage<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTypeStages")
+            .raw_call("getEventBuyers")
             .argument(&event_id)
-            .argument(&ticket_type_id)
             .original_result()
     }
 
-    pub fn get_all_stages<
+    pub fn ticket_record<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
         event_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketStage<Env::Api>>> {
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TicketRecord<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllStages")
+            .raw_call("getTicketRecord")
             .argument(&event_id)
+            .argument(&nonce)
             .original_result()
     }
 
-    pub fn get_all_income_tokens(
+    pub fn checked_in_tickets<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllIncomeTokens")
+            .raw_call("getCheckedInTickets")
+            .argument(&event_id)
             .original_result()
     }
 
-    pub fn get_all_income_payments<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    pub fn external_orders<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
-        token: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getIncomePayment")
-            .argument(&token)
+            .raw_call("getExternalOrders")
+            .argument(&event_id)
             .original_result()
     }
 
-    pub fn whitelisted_size<
+    pub fn external_order_tickets<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-        ticket_type_id: Arg1,
-        ticket_stage_id: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        external_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("whitelistSize")
+            .raw_call("getExternalOrderTickets")
             .argument(&event_id)
-            .argument(&ticket_type_id)
-            .argument(&ticket_stage_id)
+            .argument(&external_id)
             .original_result()
     }
 
-    pub fn create_event<
+    pub fn refund_escrow<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<EventArgs>,
     >(
         self,
         event_id: Arg0,
-        token_name: Arg1,
-        token_ticker: Arg2,
-        args: Arg3,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, RefundEscrow<Env::Api>>>> {
         self.wrapped_tx
-            .raw_call("createEvent")
+            .payment(NotPayable)
+            .raw_call("getRefundEscrow")
             .argument(&event_id)
-            .argument(&token_name)
-            .argument(&token_ticker)
+            .original_result()
+    }
+
+    pub fn escrowed_affiliates<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEscrowedAffiliates")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn escrowed_commissions<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        affiliate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEscrowedCommissions")
+            .argument(&event_id)
+            .argument(&affiliate)
+            .original_result()
+    }
+
+    pub fn refund_window_closed<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRefundWindowClosed")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn archived_events(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArchivedEvents")
+            .original_result()
+    }
+
+    pub fn event_summary<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EventSummary<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventSummary")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn pending_cleanups<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PendingCleanup<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingCleanups")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn referral_codes<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralCodes")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn referral_code<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        code: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReferralCode<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralCode")
+            .argument(&event_id)
+            .argument(&code)
+            .original_result()
+    }
+
+    pub fn affiliate_balance<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        affiliate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAffiliateBalance")
+            .argument(&affiliate)
+            .original_result()
+    }
+
+    pub fn max_referral_commission(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxReferralCommission")
+            .original_result()
+    }
+
+    pub fn airdrop_jobs<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAirdropJobs")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn airdrop_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        job_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AirdropJob<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAirdropProgress")
+            .argument(&event_id)
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn airdrop_failures<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        job_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, AirdropFailure<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAirdropFailures")
+            .argument(&event_id)
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn admins(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmins")
+            .original_result()
+    }
+
+    pub fn event_role_members<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<Role>,
+    >(
+        self,
+        event_id: Arg0,
+        role: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventRoleMembers")
+            .argument(&event_id)
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn event_paused<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isEventPaused")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFees")
+            .original_result()
+    }
+
+    pub fn income(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIncome")
+            .original_result()
+    }
+
+    pub fn platform_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlatformFees")
+            .original_result()
+    }
+
+    pub fn signer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSigner")
+            .original_result()
+    }
+
+    pub fn aggregator(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAggregator")
+            .original_result()
+    }
+
+    pub fn payment_providers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPaymentProviders")
+            .original_result()
+    }
+
+    pub fn standalone(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isStandalone")
+            .original_result()
+    }
+
+    pub fn fee_recipient(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeRecipient")
+            .original_result()
+    }
+
+    pub fn is_whitelisted<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        address: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWhitelisted")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_event_schema_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventSchemaVersion")
+            .original_result()
+    }
+
+    pub fn get_events(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EventDetails<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEvents")
+            .original_result()
+    }
+
+    pub fn get_event_schedule_view<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EventSchedule<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventSchedule")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn get_types<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketType<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTypes")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn get_type_stages<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketStage<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTypeStages")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .original_result()
+    }
+
+    pub fn get_all_stages<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketStage<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllStages")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn get_event_snapshot<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EventSnapshot<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventSnapshot")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn can_buy<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<usize>,
+    >(
+        self,
+        address: Arg0,
+        event_id: Arg1,
+        ticket_type_id: Arg2,
+        ticket_stage_id: Arg3,
+        quantity: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BuyEligibility<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canBuy")
+            .argument(&address)
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&quantity)
+            .original_result()
+    }
+
+    pub fn get_quote<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        quantity: Arg3,
+        token: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, PriceQuote<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuote")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&quantity)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn get_stage_report<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StageReport<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStageReport")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .original_result()
+    }
+
+    pub fn get_events_page<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<OptionalValue<EventStatus>>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+        status: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EventDetails<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEventsPage")
+            .argument(&from)
+            .argument(&size)
+            .argument(&status)
+            .original_result()
+    }
+
+    pub fn get_types_page<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketType<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTypesPage")
+            .argument(&event_id)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_all_stages_page<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<bool>,
+    >(
+        self,
+        event_id: Arg0,
+        from: Arg1,
+        size: Arg2,
+        active_only: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TicketStage<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllStagesPage")
+            .argument(&event_id)
+            .argument(&from)
+            .argument(&size)
+            .argument(&active_only)
+            .original_result()
+    }
+
+    pub fn get_allowed_users_page<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        from: Arg3,
+        size: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedUsersPage")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_all_income_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllIncomeTokens")
+            .original_result()
+    }
+
+    pub fn get_all_income_payments<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIncomePayment")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn whitelisted_size<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("whitelistSize")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .original_result()
+    }
+
+    pub fn create_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<EventArgs>,
+    >(
+        self,
+        event_id: Arg0,
+        token_name: Arg1,
+        token_ticker: Arg2,
+        args: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createEvent")
+            .argument(&event_id)
+            .argument(&token_name)
+            .argument(&token_ticker)
+            .argument(&args)
+            .original_result()
+    }
+
+    pub fn create_ticket_type<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<TicketTypeArgs<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createTicketType")
+            .argument(&event_id)
+            .argument(&args)
+            .original_result()
+    }
+
+    pub fn create_ticket_stage<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<TicketStageArgs<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        args: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createTicketStage")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
             .argument(&args)
             .original_result()
     }
 
-    pub fn create_ticket_type<
+    pub fn configure_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, TicketTypeConfig<Env::Api>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_types: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("configureEvent")
+            .argument(&event_id)
+            .argument(&ticket_types)
+            .original_result()
+    }
+
+    pub fn remove_ticket_type<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTicketType")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .original_result()
+    }
+
+    pub fn remove_ticket_stage<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTicketStage")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .original_result()
+    }
+
+    pub fn clean_up_ticket_data_endpoint<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        batch_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cleanUpTicketData")
+            .argument(&event_id)
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn add_event_buyers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        event_id: Arg0,
+        buyers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addEventBuyers")
+            .argument(&event_id)
+            .argument(&buyers)
+            .original_result()
+    }
+
+    pub fn edit_ticket_type<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<TicketTypeArgs<Env::Api>>,
     >(
@@ -557,13 +1521,51 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("createTicketType")
+            .raw_call("editTicketType")
             .argument(&event_id)
             .argument(&args)
             .original_result()
     }
 
-    pub fn create_ticket_stage<
+    pub fn refresh_ticket_type_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        batch_size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refreshTicketTypeMetadata")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn add_ticket_type_nonces<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        nonces: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTicketTypeNonces")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&nonces)
+            .original_result()
+    }
+
+    pub fn edit_ticket_stage<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<TicketStageArgs<Env::Api>>,
@@ -575,140 +1577,374 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("createTicketStage")
+            .raw_call("editTicketStage")
             .argument(&event_id)
             .argument(&ticket_type_id)
             .argument(&args)
             .original_result()
     }
 
-    pub fn remove_ticket_type<
+    pub fn edit_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EventArgs>,
+    >(
+        self,
+        event_id: Arg0,
+        args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("editEvent")
+            .argument(&event_id)
+            .argument(&args)
+            .original_result()
+    }
+
+    pub fn set_event_schedule<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        event_id: Arg0,
+        venue: Arg1,
+        start_time: Arg2,
+        end_time: Arg3,
+        doors_open_time: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEventSchedule")
+            .argument(&event_id)
+            .argument(&venue)
+            .argument(&start_time)
+            .argument(&end_time)
+            .argument(&doors_open_time)
+            .original_result()
+    }
+
+    pub fn set_event_status<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EventStatus>,
+    >(
+        self,
+        event_id: Arg0,
+        status: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEventStatus")
+            .argument(&event_id)
+            .argument(&status)
+            .original_result()
+    }
+
+    pub fn check_in_tickets<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        event_id: Arg0,
+        nonces: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("checkInTickets")
+            .argument(&event_id)
+            .argument(&nonces)
+            .original_result()
+    }
+
+    pub fn add_to_whitelist<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
     >(
         self,
         event_id: Arg0,
         ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        wallets: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeTicketType")
+            .raw_call("addWhitelists")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&wallets)
+            .original_result()
+    }
+
+    pub fn remove_from_whitelist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        event_id: Arg0,
+        ticket_type_id: Arg1,
+        ticket_stage_id: Arg2,
+        wallets: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeWhitelists")
+            .argument(&event_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&wallets)
+            .original_result()
+    }
+
+    pub fn claim_income<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimIncome")
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn pause_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseEvent")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn unpause_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        event_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseEvent")
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+        event_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+        event_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .argument(&event_id)
+            .original_result()
+    }
+
+    pub fn archive_event<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        event_id: Arg0,
+        batch_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("archiveEvent")
+            .argument(&event_id)
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn create_airdrop<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<bool>,
+    >(
+        self,
+        event_id: Arg0,
+        job_id: Arg1,
+        ticket_type_id: Arg2,
+        ticket_stage_id: Arg3,
+        respect_limits: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createAirdrop")
+            .argument(&event_id)
+            .argument(&job_id)
+            .argument(&ticket_type_id)
+            .argument(&ticket_stage_id)
+            .argument(&respect_limits)
+            .original_result()
+    }
+
+    pub fn add_airdrop_recipients<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u32>>>,
+    >(
+        self,
+        event_id: Arg0,
+        job_id: Arg1,
+        recipients: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAirdropRecipients")
             .argument(&event_id)
-            .argument(&ticket_type_id)
+            .argument(&job_id)
+            .argument(&recipients)
             .original_result()
     }
 
-    pub fn remove_ticket_stage<
+    pub fn continue_airdrop<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-        ticket_type_id: Arg1,
-        ticket_stage_id: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        job_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AirdropJob<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeTicketStage")
+            .raw_call("continueAirdrop")
             .argument(&event_id)
-            .argument(&ticket_type_id)
-            .argument(&ticket_stage_id)
+            .argument(&job_id)
             .original_result()
     }
 
-    pub fn edit_ticket_type<
+    pub fn register_referral_code<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<TicketTypeArgs<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<u32>,
     >(
         self,
         event_id: Arg0,
-        args: Arg1,
+        code: Arg1,
+        affiliate: Arg2,
+        commission: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("editTicketType")
+            .raw_call("registerReferralCode")
             .argument(&event_id)
-            .argument(&args)
+            .argument(&code)
+            .argument(&affiliate)
+            .argument(&commission)
             .original_result()
     }
 
-    pub fn edit_ticket_stage<
+    pub fn remove_referral_code<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<TicketStageArgs<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-        ticket_type_id: Arg1,
-        args: Arg2,
+        code: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("editTicketStage")
+            .raw_call("removeReferralCode")
             .argument(&event_id)
-            .argument(&ticket_type_id)
-            .argument(&args)
+            .argument(&code)
             .original_result()
     }
 
-    pub fn edit_event<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<EventArgs>,
+    pub fn set_max_referral_commission<
+        Arg0: ProxyArg<u32>,
     >(
         self,
-        event_id: Arg0,
-        args: Arg1,
+        commission: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("editEvent")
-            .argument(&event_id)
-            .argument(&args)
+            .raw_call("setMaxReferralCommission")
+            .argument(&commission)
             .original_result()
     }
 
-    pub fn add_to_whitelist<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
-    >(
+    pub fn claim_affiliate_earnings(
         self,
-        event_id: Arg0,
-        ticket_type_id: Arg1,
-        ticket_stage_id: Arg2,
-        wallets: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("addWhitelists")
-            .argument(&event_id)
-            .argument(&ticket_type_id)
-            .argument(&ticket_stage_id)
-            .argument(&wallets)
+            .raw_call("claimAffiliateEarnings")
             .original_result()
     }
 
-    pub fn remove_from_whitelist<
+    pub fn get_affiliate_sales<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         event_id: Arg0,
-        ticket_type_id: Arg1,
-        ticket_stage_id: Arg2,
-        wallets: Arg3,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        affiliate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ReferralCode<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeWhitelists")
+            .raw_call("getAffiliateSales")
             .argument(&event_id)
-            .argument(&ticket_type_id)
-            .argument(&ticket_stage_id)
-            .argument(&wallets)
+            .argument(&affiliate)
             .original_result()
     }
 }
@@ -756,13 +1992,35 @@ where
     pub bot_protection: bool,
 }
 
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSchedule<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub venue: ManagedBuffer<Api>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub doors_open_time: u64,
+    pub status: EventStatus,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    Upcoming,
+    Live,
+    Finished,
+    Cancelled,
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketStage<Api>
 where
     Api: ManagedTypeApi,
 {
-    pub prices: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub prices: ManagedVec<Api, StagePrice<Api>>,
     pub id: ManagedBuffer<Api>,
     pub ticket_type_id: ManagedBuffer<Api>,
     pub has_whitelist: bool,
@@ -774,6 +2032,17 @@ where
     pub active: bool,
 }
 
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StagePrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub amount: BigUint<Api>,
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketType<Api>
@@ -789,6 +2058,299 @@ where
     pub mint_count: u32,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MetadataRefreshData {
+    pub processed: u32,
+    pub total: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSummary<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: ManagedBuffer<Api>,
+    pub token: TokenIdentifier<Api>,
+    pub status: EventStatus,
+    pub mint_count: u32,
+    pub max_capacity: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub archived_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Admin,
+    Organizer,
+    Operator,
+    Scanner,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ReceiptEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub provider: ManagedAddress<Api>,
+    pub recipient: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub currency: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ReferralCode<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub code: ManagedBuffer<Api>,
+    pub affiliate: ManagedAddress<Api>,
+    pub commission: u32,
+    pub orders: u32,
+    pub tickets: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RefundEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub buyer: ManagedAddress<Api>,
+    pub ticket: EsdtTokenPayment<Api>,
+    pub refund: EgldOrEsdtTokenPayment<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MintEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub event: Event<Api>,
+    pub ticket_type: TicketType<Api>,
+    pub ticket_stage: Option<TicketStage<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BuyEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: TokenIdentifier<Api>,
+    pub payments: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub timestamp: u64,
+    pub epoch: u64,
+    pub event: Event<Api>,
+    pub ticket_type: TicketType<Api>,
+    pub swap: Option<SwapSummary<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SwapSummary<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub input: EgldOrEsdtTokenPayment<Api>,
+    pub output: EgldOrEsdtTokenPayment<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataUpdateState {
+    Disabled,
+    Dynamic,
+    Enabled,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub buyer: ManagedAddress<Api>,
+    pub ticket_type_id: ManagedBuffer<Api>,
+    pub ticket_stage_id: ManagedBuffer<Api>,
+    pub price: EgldOrEsdtTokenPayment<Api>,
+    pub platform_share: BigUint<Api>,
+    pub organizer_share: BigUint<Api>,
+    pub affiliate: Option<ManagedAddress<Api>>,
+    pub commission: BigUint<Api>,
+    pub referral_code: Option<ManagedBuffer<Api>>,
+    pub order_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RefundEscrow<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub platform_fees: BigUint<Api>,
+    pub income: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PendingCleanup<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ticket_type_id: ManagedBuffer<Api>,
+    pub ticket_stage_id: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AirdropJob<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: ManagedBuffer<Api>,
+    pub event_id: ManagedBuffer<Api>,
+    pub ticket_type_id: ManagedBuffer<Api>,
+    pub ticket_stage_id: ManagedBuffer<Api>,
+    pub respect_limits: bool,
+    pub total: u32,
+    pub processed: u32,
+    pub minted: u32,
+    pub failed: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AirdropFailure<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub quantity: u32,
+    pub reason: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventDetails<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub event: Event<Api>,
+    pub schedule: EventSchedule<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub event: Event<Api>,
+    pub schedule: EventSchedule<Api>,
+    pub token: TokenIdentifier<Api>,
+    pub remaining: u32,
+    pub ticket_types: ManagedVec<Api, TicketTypeSnapshot<Api>>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketTypeSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ticket_type: TicketType<Api>,
+    pub remaining: u32,
+    pub stages: ManagedVec<Api, TicketStageSnapshot<Api>>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketStageSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub stage: TicketStage<Api>,
+    pub time_status: StageTimeStatus,
+    pub remaining: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum StageTimeStatus {
+    NotStarted,
+    Ongoing,
+    Ended,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BuyEligibility<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub eligible: bool,
+    pub reason: ManagedBuffer<Api>,
+    pub stage_remaining: u32,
+    pub type_remaining: u32,
+    pub event_remaining: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PriceQuote<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub price_per_ticket: BigUint<Api>,
+    pub total_amount: BigUint<Api>,
+    pub min_swap_token: EgldOrEsdtTokenIdentifier<Api>,
+    pub min_swap_output: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StageReport<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ticket_type_id: ManagedBuffer<Api>,
+    pub ticket_stage_id: ManagedBuffer<Api>,
+    pub mint_count: u32,
+    pub stats: StageStats,
+    pub revenue: ManagedVec<Api, StageRevenue<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct StageStats {
+    pub sold: u32,
+    pub comps: u32,
+    pub refunds: u32,
+    pub unique_buyers: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StageRevenue<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub gross_revenue: BigUint<Api>,
+    pub platform_fees: BigUint<Api>,
+    pub net_revenue: BigUint<Api>,
+    pub refunded: BigUint<Api>,
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct EventArgs {
@@ -801,7 +2363,7 @@ pub struct EventArgs {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketTypeArgs<Api>
 where
     Api: ManagedTypeApi,
@@ -820,7 +2382,7 @@ pub struct TicketStageArgs<Api>
 where
     Api: ManagedTypeApi,
 {
-    pub prices: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub prices: ManagedVec<Api, StagePrice<Api>>,
     pub id: ManagedBuffer<Api>,
     pub has_whitelist: bool,
     pub max_per_user: u32,
@@ -829,3 +2391,13 @@ where
     pub end_time: u64,
     pub active: bool,
 }
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketTypeConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ticket_type: TicketTypeArgs<Api>,
+    pub stages: ManagedVec<Api, TicketStageArgs<Api>>,
+}
@@ -0,0 +1,222 @@
+use crate::structs::{
+    AirdropFailure, AirdropJob, AirdropRecipient, Event, TicketStage, TicketType,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+const MIN_GAS_PER_RECIPIENT: u64 = 5_000_000;
+const GAS_PER_AIRDROP_TICKET: u64 = 3_000_000;
+// Keeps a single recipient within what one call can mint
+const MAX_AIRDROP_QUANTITY: u32 = 100;
+
+#[multiversx_sc::module]
+pub trait AirdropModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::common::CommonModule
+    + crate::pause::PauseModule
+    + crate::roles::RolesModule
+{
+    #[endpoint(createAirdrop)]
+    fn create_airdrop(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        respect_limits: bool,
+    ) {
+        self.require_operator(event_id);
+        self.is_event_valid(event_id);
+        self.is_ticket_type_valid(event_id, ticket_type_id);
+        if !ticket_stage_id.is_empty() {
+            self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
+        }
+        require!(
+            self.airdrop_jobs(event_id).insert(job_id.clone()),
+            "The airdrop {} exists already!",
+            job_id
+        );
+
+        self.airdrop_job(event_id, job_id).set(AirdropJob {
+            id: job_id.clone(),
+            event_id: event_id.clone(),
+            ticket_type_id: ticket_type_id.clone(),
+            ticket_stage_id: ticket_stage_id.clone(),
+            respect_limits,
+            total: 0,
+            processed: 0,
+            minted: 0,
+            failed: 0,
+        });
+    }
+
+    #[endpoint(addAirdropRecipients)]
+    fn add_airdrop_recipients(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+        recipients: MultiValueEncoded<MultiValue2<ManagedAddress, u32>>,
+    ) {
+        self.require_operator(event_id);
+        let job_map = self.does_airdrop_exists(event_id, job_id);
+        let mut job = job_map.get();
+        let mut map_recipients = self.airdrop_recipients(event_id, job_id);
+
+        for recipient in recipients {
+            let (address, quantity) = recipient.into_tuple();
+            require!(quantity > 0, "The quantity has to be higher than 0!");
+            require!(
+                quantity <= MAX_AIRDROP_QUANTITY,
+                "The quantity per recipient is limited to {}!",
+                MAX_AIRDROP_QUANTITY
+            );
+            map_recipients.push(&AirdropRecipient { address, quantity });
+            job.total += 1;
+        }
+
+        job_map.set(job);
+    }
+
+    #[endpoint(continueAirdrop)]
+    fn continue_airdrop(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+    ) -> AirdropJob<Self::Api> {
+        self.require_operator(event_id);
+        let job_map = self.does_airdrop_exists(event_id, job_id);
+        let mut job = job_map.get();
+        require!(
+            job.processed < job.total,
+            "The airdrop is already completed!"
+        );
+
+        let mut event = self.does_event_exists(event_id);
+        let mut ticket_type = self.does_ticket_type_exists(event_id, &job.ticket_type_id);
+        let mut ticket_stage = if job.ticket_stage_id.is_empty() {
+            None
+        } else {
+            Some(self.is_ticket_stage_valid(event_id, &job.ticket_type_id, &job.ticket_stage_id))
+        };
+        self.require_not_paused(event_id);
+        self.require_event_on_sale(&event);
+
+        let map_recipients = self.airdrop_recipients(event_id, job_id);
+        let mut map_failures = self.airdrop_failures(event_id, job_id);
+        while job.processed < job.total {
+            let recipient = map_recipients.get(job.processed as usize + 1);
+            let gas_needed =
+                MIN_GAS_PER_RECIPIENT + GAS_PER_AIRDROP_TICKET * recipient.quantity as u64;
+            if self.blockchain().get_gas_left() < gas_needed {
+                break;
+            }
+
+            job.processed += 1;
+            let quantity = recipient.quantity as usize;
+
+            let error = if self.blockchain().is_smart_contract(&recipient.address) {
+                Some(ManagedBuffer::from(
+                    "Only user accounts are allowed to mint",
+                ))
+            } else {
+                self.airdrop_error(
+                    &job,
+                    &recipient,
+                    &event,
+                    &ticket_type,
+                    ticket_stage.as_ref(),
+                )
+            };
+
+            if let Some(reason) = error {
+                job.failed += 1;
+                map_failures.push(&AirdropFailure {
+                    address: recipient.address,
+                    quantity: recipient.quantity,
+                    reason,
+                });
+                continue;
+            }
+
+            let payments = self.send_nft(
+                &mut event,
+                &mut ticket_type,
+                ticket_stage.as_mut(),
+                &recipient.address,
+                quantity,
+            );
+            self.comped_tickets(event_id, &ticket_type.id)
+                .update(|count| *count += recipient.quantity);
+            if !job.ticket_stage_id.is_empty() {
+                self.record_stage_comps(event_id, &ticket_type.id, &job.ticket_stage_id, quantity);
+            }
+            job.minted += recipient.quantity;
+
+            self.emit_buy(
+                &payments,
+                &EgldOrEsdtTokenIdentifier::egld(),
+                &recipient.address,
+                &BigUint::zero(),
+                &BigUint::zero(),
+                &BigUint::zero(),
+                &event.token,
+                job_id,
+                &event,
+                &ticket_type,
+                &None,
+            );
+        }
+
+        job_map.set(&job);
+        job
+    }
+
+    fn airdrop_error(
+        &self,
+        job: &AirdropJob<Self::Api>,
+        recipient: &AirdropRecipient<Self::Api>,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: Option<&TicketStage<Self::Api>>,
+    ) -> Option<ManagedBuffer> {
+        let quantity = recipient.quantity as usize;
+        match ticket_stage {
+            Some(stage) => {
+                if job.respect_limits {
+                    if let Some(error) = self.buys_limits_error(
+                        &recipient.address,
+                        quantity,
+                        event,
+                        ticket_type,
+                        stage,
+                    ) {
+                        return Some(error);
+                    }
+                }
+                self.sold_out_error(event, ticket_type, stage, quantity)
+            }
+            None => {
+                if job.respect_limits {
+                    if let Some(error) =
+                        self.type_limits_error(&recipient.address, quantity, event, ticket_type)
+                    {
+                        return Some(error);
+                    }
+                }
+                self.type_sold_out_error(event, ticket_type, quantity)
+            }
+        }
+    }
+
+    fn does_airdrop_exists(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<AirdropJob<Self::Api>> {
+        let map = self.airdrop_job(event_id, job_id);
+        require!(!map.is_empty(), "The airdrop {} is invalid!", job_id);
+        map
+    }
+}
@@ -0,0 +1,209 @@
+use crate::structs::{Event, EventStatus, EventSummary, Role};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait ArchiveModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::common::CommonModule
+    + crate::pause::PauseModule
+    + crate::roles::RolesModule
+{
+    #[endpoint(archiveEvent)]
+    fn archive_event(&self, event_id: &ManagedBuffer, batch_size: usize) -> bool {
+        self.require_admin();
+        let event = self.does_event_exists(event_id);
+        let status = self.get_event_status(&event);
+        require!(
+            status == EventStatus::Finished || status == EventStatus::Cancelled,
+            "Only finished or cancelled events can be archived!"
+        );
+
+        self.event_paused(event_id).set(true);
+
+        let mut budget = batch_size;
+        let done = self.release_refund_escrow(event_id, &mut budget)
+            && self.archive_ticket_types(event_id, &mut budget)
+            && self.clean_up_ticket_data(event_id, &mut budget)
+            && self.archive_buyers(event_id, &mut budget)
+            && self.archive_tickets(&event, &mut budget)
+            && self.archive_external_orders(event_id, &mut budget)
+            && self.archive_airdrops(event_id, &mut budget)
+            && self.archive_referrals(event_id, &mut budget);
+
+        if done {
+            self.finish_archive(event, status);
+        }
+
+        done
+    }
+
+    // Type and stage counters are already gone through the cleanup queue
+    fn archive_buyers(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let mut buyers = self.event_buyers(event_id);
+
+        while !buyers.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            let buyer = buyers.get_by_index(buyers.len());
+            self.buys_per_event(&buyer, event_id).clear();
+            buyers.swap_remove(&buyer);
+        }
+
+        true
+    }
+
+    fn archive_tickets(&self, event: &Event<Self::Api>, budget: &mut usize) -> bool {
+        let cursor_map = self.archive_cursor(&event.id);
+        let last_nonce = self.next_nonce(&event.token).get() as u64;
+        let mut nonce = cursor_map.get();
+
+        while nonce + 1 < last_nonce {
+            if *budget == 0 {
+                cursor_map.set(nonce);
+                return false;
+            }
+            *budget -= 1;
+
+            nonce += 1;
+            self.ticket_record(&event.id, nonce).clear();
+            self.referral_order(&event.id, nonce).clear();
+        }
+        cursor_map.set(nonce);
+
+        let mut checked_in = self.checked_in_tickets(&event.id);
+        while !checked_in.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            let nonce = checked_in.get_by_index(checked_in.len());
+            checked_in.swap_remove(&nonce);
+        }
+
+        true
+    }
+
+    fn archive_external_orders(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let mut orders = self.external_orders(event_id);
+
+        while !orders.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            let external_id = orders.get_by_index(orders.len());
+            self.external_order_tickets(event_id, &external_id).clear();
+            orders.swap_remove(&external_id);
+        }
+
+        true
+    }
+
+    fn archive_airdrops(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let mut jobs = self.airdrop_jobs(event_id);
+
+        while !jobs.is_empty() {
+            let job_id = jobs.get_by_index(jobs.len());
+            let mut recipients = self.airdrop_recipients(event_id, &job_id);
+            let mut failures = self.airdrop_failures(event_id, &job_id);
+
+            while !recipients.is_empty() || !failures.is_empty() {
+                if *budget == 0 {
+                    return false;
+                }
+                *budget -= 1;
+
+                if !recipients.is_empty() {
+                    recipients.swap_remove(recipients.len());
+                } else {
+                    failures.swap_remove(failures.len());
+                }
+            }
+
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            self.airdrop_job(event_id, &job_id).clear();
+            jobs.swap_remove(&job_id);
+        }
+
+        true
+    }
+
+    fn archive_referrals(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let mut codes = self.referral_codes(event_id);
+
+        while !codes.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            let code = codes.get_by_index(codes.len());
+            let referral = self.referral_code(event_id, &code).take();
+            self.affiliate_codes(event_id, &referral.affiliate).clear();
+            codes.swap_remove(&code);
+        }
+
+        true
+    }
+
+    fn archive_ticket_types(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let ticket_types = self.ticket_types(event_id);
+
+        while !ticket_types.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+
+            let ticket_type_id = ticket_types.get_by_index(ticket_types.len());
+            let stages_count = self.detach_ticket_type(event_id, &ticket_type_id);
+            *budget = budget.saturating_sub(1 + stages_count);
+        }
+
+        true
+    }
+
+    fn finish_archive(&self, event: Event<Self::Api>, status: EventStatus) {
+        let schedule = self.get_event_schedule(&event.id);
+        let summary = EventSummary {
+            id: event.id.clone(),
+            token: event.token.clone(),
+            status,
+            mint_count: event.mint_count,
+            max_capacity: event.max_capacity,
+            start_time: schedule.start_time,
+            end_time: schedule.end_time,
+            archived_at: self.blockchain().get_block_timestamp(),
+        };
+
+        for role in [Role::Organizer, Role::Operator, Role::Scanner] {
+            self.event_role_members(&event.id, role).clear();
+        }
+        self.transfer_wallets(&event.id).clear();
+        self.archive_cursor(&event.id).clear();
+        self.refund_window_closed(&event.id).clear();
+        self.event_paused(&event.id).clear();
+        self.event_schedule(&event.id).clear();
+        self.next_nonce(&event.token).clear();
+        self.collections().swap_remove(&event.token);
+        self.metadata_update_state(&event.id).clear();
+        self.token_manager(&event.id).clear();
+        self.event_by_id(&event.id).clear();
+        self.events().swap_remove(&event.id);
+
+        self.archived_events().insert(event.id.clone());
+        self.event_summary(&event.id).set(&summary);
+        self.emit_archive_event(&summary);
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{aggregator::*, manager_proxy};
-use crate::{structs::*, NFT_AMOUNT, ROYALTIES_MAX};
+use crate::{structs::*, NFT_AMOUNT, RECEIPT_SIGNATURE_TAG, ROYALTIES_MAX};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
-pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsModule {
+pub trait CommonModule:
+    crate::storage::StorageModule + crate::events::EventsModule + crate::pause::PauseModule
+{
     fn is_event_valid(&self, event_id: &ManagedBuffer) -> SingleValueMapper<Event<Self::Api>> {
         let map = self.event_by_id(event_id);
         require!(
@@ -63,6 +65,215 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         map.get()
     }
 
+    fn insert_ticket_type(&self, event_id: &ManagedBuffer, args: &TicketTypeArgs<Self::Api>) {
+        let mut map_types = self.ticket_types(event_id);
+        require!(
+            !map_types.contains(&args.id),
+            "This ID has been created already!"
+        );
+        self.require_not_cleaning_up(event_id, &args.id, None);
+        map_types.insert(args.id.clone());
+
+        let ticket_type = TicketType {
+            id: args.id.clone(),
+            base_name: args.base_name.clone(),
+            image: args.image.clone(),
+            royalties: args.royalties.clone(),
+            max_per_user: args.max_per_user,
+            mint_limit: args.mint_limit,
+            mint_count: 0,
+        };
+
+        self.ticket_type_by_id(event_id, &args.id).set(&ticket_type);
+        self.emit_ticket_type(&ticket_type, event_id);
+    }
+
+    fn insert_ticket_stage(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        args: &TicketStageArgs<Self::Api>,
+    ) {
+        let mut map_stages = self.ticket_stages(event_id, ticket_type_id);
+        require!(!map_stages.contains_key(&args.id), "The key already exists");
+        self.require_not_cleaning_up(event_id, ticket_type_id, Some(&args.id));
+        let ticket_stage = TicketStage {
+            id: args.id.clone(),
+            prices: args.prices.clone(),
+            has_whitelist: args.has_whitelist,
+            start_time: args.start_time,
+            end_time: args.end_time,
+            active: args.active,
+            max_per_user: args.max_per_user,
+            mint_limit: args.mint_limit,
+            ticket_type_id: ticket_type_id.clone(),
+            mint_count: 0,
+        };
+        self.emit_ticket_stage(&ticket_stage, event_id);
+        map_stages.insert(args.id.clone(), ticket_stage);
+    }
+
+    fn detach_ticket_type(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+    ) -> usize {
+        let mut ticket_stages = self.ticket_stages(event_id, ticket_type_id);
+        let stages_count = ticket_stages.len();
+        for ticket_stage_id in ticket_stages.keys() {
+            self.detach_ticket_stage(event_id, ticket_type_id, &ticket_stage_id);
+        }
+        ticket_stages.clear();
+
+        self.pending_cleanups(event_id).push(&PendingCleanup {
+            ticket_type_id: ticket_type_id.clone(),
+            ticket_stage_id: ManagedBuffer::new(),
+        });
+        self.ticket_types(event_id).swap_remove(ticket_type_id);
+        self.ticket_type_by_id(event_id, ticket_type_id).clear();
+
+        stages_count
+    }
+
+    fn detach_ticket_stage(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) {
+        self.pending_cleanups(event_id).push(&PendingCleanup {
+            ticket_type_id: ticket_type_id.clone(),
+            ticket_stage_id: ticket_stage_id.clone(),
+        });
+    }
+
+    fn require_not_cleaning_up(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: Option<&ManagedBuffer>,
+    ) {
+        for cleanup in self.pending_cleanups(event_id).iter() {
+            require!(
+                &cleanup.ticket_type_id != ticket_type_id
+                    || ticket_stage_id.is_some_and(|id| &cleanup.ticket_stage_id != id),
+                "The ID is still being cleaned up!"
+            );
+        }
+    }
+
+    // Every storage clear costs one unit of the budget, the buyers cursor resumes partial work
+    fn clean_up_ticket_data(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        let mut pending = self.pending_cleanups(event_id);
+        let buyers = self.event_buyers(event_id);
+        let cursor_map = self.cleanup_cursor(event_id);
+
+        while !pending.is_empty() {
+            let cleanup = pending.get(pending.len());
+            let type_id = &cleanup.ticket_type_id;
+            let stage_id = &cleanup.ticket_stage_id;
+            let is_stage = !stage_id.is_empty();
+
+            if is_stage {
+                let mut wallets = self.whitelist_wallets(event_id, type_id, stage_id);
+                while !wallets.is_empty() {
+                    if *budget == 0 {
+                        return false;
+                    }
+                    *budget -= 1;
+
+                    let wallet = wallets.get_by_index(wallets.len());
+                    wallets.swap_remove(&wallet);
+                }
+            } else {
+                let mut nonces = self.ticket_type_nonces(event_id, type_id);
+                while !nonces.is_empty() {
+                    if *budget == 0 {
+                        return false;
+                    }
+                    *budget -= 1;
+
+                    let nonce = nonces.get_by_index(nonces.len());
+                    nonces.swap_remove(&nonce);
+                }
+            }
+
+            let mut cursor = cursor_map.get();
+            while cursor < buyers.len() {
+                if *budget == 0 {
+                    cursor_map.set(cursor);
+                    return false;
+                }
+                *budget -= 1;
+
+                cursor += 1;
+                let buyer = buyers.get_by_index(cursor);
+                if is_stage {
+                    self.buys_per_ticket_stage(&buyer, event_id, type_id, stage_id)
+                        .clear();
+                    self.stage_buyer(&buyer, event_id, type_id, stage_id)
+                        .clear();
+                } else {
+                    self.buys_per_ticket_type(&buyer, event_id, type_id).clear();
+                }
+            }
+
+            if *budget == 0 {
+                cursor_map.set(cursor);
+                return false;
+            }
+            *budget -= 1;
+
+            if is_stage {
+                self.clear_stage_analytics(event_id, type_id, stage_id);
+            } else {
+                self.metadata_cursor(event_id, type_id).clear();
+                self.comped_tickets(event_id, type_id).clear();
+            }
+            cursor_map.clear();
+            pending.swap_remove(pending.len());
+        }
+
+        true
+    }
+
+    // Keeps the metadata cursor from skipping the nonce that swap_remove moves into the gap
+    // Refunded tickets are burned and dropped from both the records and the type nonces
+    fn is_live_ticket(&self, event_id: &ManagedBuffer, nonce: u64) -> bool {
+        if !self.ticket_record(event_id, nonce).is_empty() {
+            return true;
+        }
+
+        self.ticket_types(event_id).iter().any(|ticket_type_id| {
+            self.ticket_type_nonces(event_id, &ticket_type_id)
+                .contains(&nonce)
+        })
+    }
+
+    fn remove_ticket_nonce(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        nonce: u64,
+    ) {
+        let mut nonces = self.ticket_type_nonces(event_id, ticket_type_id);
+        let index = nonces.get_index(&nonce);
+        if index == 0 {
+            return;
+        }
+
+        let cursor_map = self.metadata_cursor(event_id, ticket_type_id);
+        let cursor = cursor_map.get();
+        if index <= cursor {
+            if cursor >= nonces.len() {
+                cursor_map.set(cursor - 1);
+            } else {
+                cursor_map.set(index - 1);
+            }
+        }
+        nonces.swap_remove(&nonce);
+    }
+
     fn send_nft(
         &self,
         event: &mut Event<Self::Api>,
@@ -78,12 +289,13 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         let has_stage: bool = ticket_stage_opt.is_some();
         let mut nft_output_payments = ManagedVec::new();
         let map_nonce = self.next_nonce(&event.token);
+        let mut map_type_nonces = self.ticket_type_nonces(&event.id, &ticket_type.id);
         let mut nonce = map_nonce.get();
         let nft_amount = BigUint::from(NFT_AMOUNT);
         for _ in 0..nfts_to_send {
             let base_name = ticket_type.base_name.clone();
             let nft_name = self.get_nft_name(nonce, &base_name, &event);
-            let attributes = ManagedBuffer::new();
+            let attributes = self.get_nft_attributes(event, ticket_type, &nft_name, false);
             let mut uris = ManagedVec::new();
             let url_image = ticket_type.image.clone();
 
@@ -100,6 +312,7 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
             );
 
             nonce += 1;
+            map_type_nonces.insert(nft_nonce);
             nft_output_payments.push(EsdtTokenPayment::new(
                 event.token.clone(),
                 nft_nonce,
@@ -107,6 +320,7 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
             ));
         }
 
+        self.event_buyers(&event.id).insert(to.clone());
         self.buys_per_event(to, &event.id)
             .update(|counts| *counts += nfts_to_send as u32);
         self.buys_per_ticket_type(to, &event.id, &ticket_type.id)
@@ -147,6 +361,348 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         nft_output_payments
     }
 
+    fn record_stage_sale(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        buyer: &ManagedAddress,
+        quantity: usize,
+        payment: &EgldOrEsdtTokenPayment,
+        platform_amount: &BigUint,
+        organizer_amount: &BigUint,
+    ) {
+        // A buyer only counts once per stage, even after a refund and a new purchase
+        let buyer_map = self.stage_buyer(buyer, event_id, ticket_type_id, ticket_stage_id);
+        let is_new_buyer = !buyer_map.get();
+        buyer_map.set(true);
+        self.update_stage_stats(event_id, ticket_type_id, ticket_stage_id, |stats| {
+            stats.sold += quantity as u32;
+            if is_new_buyer {
+                stats.unique_buyers += 1;
+            }
+        });
+        if payment.amount == 0 {
+            return;
+        }
+
+        let mut map = self.stage_revenue(event_id, ticket_type_id, ticket_stage_id);
+        let mut revenue = map
+            .get(&payment.token_identifier)
+            .unwrap_or_else(|| StageRevenue::new(payment.token_identifier.clone()));
+        revenue.gross_revenue += &payment.amount;
+        revenue.platform_fees += platform_amount;
+        revenue.net_revenue += organizer_amount;
+        map.insert(payment.token_identifier.clone(), revenue);
+    }
+
+    fn record_stage_comps(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+    ) {
+        self.update_stage_stats(event_id, ticket_type_id, ticket_stage_id, |stats| {
+            stats.comps += quantity as u32
+        });
+    }
+
+    fn record_stage_refund(
+        &self,
+        event_id: &ManagedBuffer,
+        record: &TicketRecord<Self::Api>,
+        refunded: &BigUint,
+    ) {
+        if self
+            .stage_stats(event_id, &record.ticket_type_id, &record.ticket_stage_id)
+            .is_empty()
+        {
+            return;
+        }
+        self.update_stage_stats(
+            event_id,
+            &record.ticket_type_id,
+            &record.ticket_stage_id,
+            |stats| stats.refunds += 1,
+        );
+
+        let mut map = self.stage_revenue(event_id, &record.ticket_type_id, &record.ticket_stage_id);
+        if let Some(mut revenue) = map.get(&record.price.token_identifier) {
+            revenue.refunded += refunded;
+            map.insert(record.price.token_identifier.clone(), revenue);
+        }
+    }
+
+    fn get_stage_stats(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) -> StageStats {
+        let map = self.stage_stats(event_id, ticket_type_id, ticket_stage_id);
+        if map.is_empty() {
+            return StageStats::default();
+        }
+
+        map.get()
+    }
+
+    fn update_stage_stats<F: FnOnce(&mut StageStats)>(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        f: F,
+    ) {
+        let mut stats = self.get_stage_stats(event_id, ticket_type_id, ticket_stage_id);
+        f(&mut stats);
+        self.stage_stats(event_id, ticket_type_id, ticket_stage_id)
+            .set(stats);
+    }
+
+    fn clear_stage_analytics(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) {
+        self.stage_stats(event_id, ticket_type_id, ticket_stage_id)
+            .clear();
+        self.stage_revenue(event_id, ticket_type_id, ticket_stage_id)
+            .clear();
+    }
+
+    fn record_tickets(
+        &self,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+        buyer: &ManagedAddress,
+        nfts: &PaymentsVec<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment,
+        price_per_nft: &BigUint,
+        referral: Option<&ReferralCode<Self::Api>>,
+    ) {
+        if !self.is_refundable_sale(event, payment) {
+            return;
+        }
+
+        let (platform_share, organizer_share, commission) =
+            self.split_ticket_price(event, payment, price_per_nft, referral);
+        let order_nonce = nfts.get(0).token_nonce;
+        if referral.is_some() {
+            self.referral_order(&event.id, order_nonce)
+                .set(nfts.len() as u32);
+        }
+        let record = TicketRecord {
+            buyer: buyer.clone(),
+            ticket_type_id: ticket_type.id.clone(),
+            ticket_stage_id: ticket_stage.id.clone(),
+            price: EgldOrEsdtTokenPayment::new(
+                payment.token_identifier.clone(),
+                payment.token_nonce,
+                price_per_nft.clone(),
+            ),
+            platform_share,
+            organizer_share,
+            affiliate: referral.map(|referral| referral.affiliate.clone()),
+            commission,
+            referral_code: referral.map(|referral| referral.code.clone()),
+            order_nonce,
+        };
+        for nft in nfts.iter() {
+            self.ticket_record(&event.id, nft.token_nonce).set(&record);
+        }
+    }
+
+    fn get_external_order(
+        &self,
+        event_id: &ManagedBuffer,
+        external_id: &ManagedBuffer,
+    ) -> Option<ManagedVec<EsdtTokenPayment>> {
+        if external_id.is_empty() || !self.external_orders(event_id).contains(external_id) {
+            return None;
+        }
+
+        Some(self.external_order_tickets(event_id, external_id).get())
+    }
+
+    fn save_external_order(
+        &self,
+        event_id: &ManagedBuffer,
+        external_id: &ManagedBuffer,
+        payments: &ManagedVec<EsdtTokenPayment>,
+    ) {
+        if external_id.is_empty() {
+            return;
+        }
+
+        self.external_orders(event_id).insert(external_id.clone());
+        self.external_order_tickets(event_id, external_id)
+            .set(payments);
+    }
+
+    fn release_ticket(&self, event: &mut Event<Self::Api>, record: &TicketRecord<Self::Api>) {
+        event.mint_count = event.mint_count.saturating_sub(1);
+        self.buys_per_event(&record.buyer, &event.id)
+            .update(|counts| *counts = counts.saturating_sub(1));
+
+        let type_map = self.ticket_type_by_id(&event.id, &record.ticket_type_id);
+        if !type_map.is_empty() {
+            type_map.update(|ticket_type| {
+                ticket_type.mint_count = ticket_type.mint_count.saturating_sub(1)
+            });
+        }
+        self.buys_per_ticket_type(&record.buyer, &event.id, &record.ticket_type_id)
+            .update(|counts| *counts = counts.saturating_sub(1));
+
+        let mut stages = self.ticket_stages(&event.id, &record.ticket_type_id);
+        if let Some(mut ticket_stage) = stages.get(&record.ticket_stage_id) {
+            ticket_stage.mint_count = ticket_stage.mint_count.saturating_sub(1);
+            stages.insert(record.ticket_stage_id.clone(), ticket_stage);
+        }
+        self.buys_per_ticket_stage(
+            &record.buyer,
+            &event.id,
+            &record.ticket_type_id,
+            &record.ticket_stage_id,
+        )
+        .update(|counts| *counts = counts.saturating_sub(1));
+    }
+
+    // Every share of a refundable ticket waits in the event escrow, so the full price comes back
+    fn reverse_ticket_income(
+        &self,
+        event_id: &ManagedBuffer,
+        record: &TicketRecord<Self::Api>,
+    ) -> EgldOrEsdtTokenPayment {
+        let token = &record.price.token_identifier;
+        if record.platform_share > 0 || record.organizer_share > 0 {
+            let mut escrow_map = self.refund_escrow(event_id);
+            let escrow = escrow_map.get(token);
+            require!(
+                escrow.as_ref().is_some_and(|escrow| {
+                    escrow.platform_fees >= record.platform_share
+                        && escrow.income >= record.organizer_share
+                }),
+                "The refund escrow cannot cover the ticket!"
+            );
+
+            let mut escrow = escrow.unwrap();
+            escrow.platform_fees -= &record.platform_share;
+            escrow.income -= &record.organizer_share;
+            if escrow.platform_fees == 0 && escrow.income == 0 {
+                escrow_map.remove(token);
+            } else {
+                escrow_map.insert(token.clone(), escrow);
+            }
+        }
+
+        if let Some(affiliate) = &record.affiliate {
+            if record.commission > 0 {
+                let mut commissions = self.escrowed_commissions(event_id, affiliate);
+                let available = commissions.get(token).unwrap_or_default();
+                require!(
+                    available >= record.commission,
+                    "The refund escrow cannot cover the ticket!"
+                );
+                if available == record.commission {
+                    commissions.remove(token);
+                } else {
+                    commissions.insert(token.clone(), available - &record.commission);
+                }
+                if commissions.is_empty() {
+                    self.escrowed_affiliates(event_id).swap_remove(affiliate);
+                }
+            }
+        }
+
+        record.price.clone()
+    }
+
+    // The order only stops counting once its last ticket is refunded
+    fn reverse_referral_sale(&self, event_id: &ManagedBuffer, record: &TicketRecord<Self::Api>) {
+        let code = match &record.referral_code {
+            Some(code) => code,
+            None => return,
+        };
+
+        let order_map = self.referral_order(event_id, record.order_nonce);
+        let remaining = order_map.get().saturating_sub(1);
+        if remaining == 0 {
+            order_map.clear();
+        } else {
+            order_map.set(remaining);
+        }
+
+        let referral_map = self.referral_code(event_id, code);
+        if referral_map.is_empty() {
+            return;
+        }
+        let mut referral = referral_map.get();
+        if Some(&referral.affiliate) != record.affiliate.as_ref() {
+            return;
+        }
+        referral.tickets = referral.tickets.saturating_sub(1);
+        if remaining == 0 {
+            referral.orders = referral.orders.saturating_sub(1);
+        }
+        referral_map.set(&referral);
+    }
+
+    // Moves the escrow into the claimable ledgers, one affiliate per unit and the rest in one
+    fn release_refund_escrow(&self, event_id: &ManagedBuffer, budget: &mut usize) -> bool {
+        self.refund_window_closed(event_id).set(true);
+
+        let mut affiliates = self.escrowed_affiliates(event_id);
+        while !affiliates.is_empty() {
+            if *budget == 0 {
+                return false;
+            }
+            *budget -= 1;
+
+            let affiliate = affiliates.get_by_index(affiliates.len());
+            let mut commissions = self.escrowed_commissions(event_id, &affiliate);
+            for (token, amount) in commissions.iter() {
+                self.credit_affiliate_balance(&affiliate, &token, &amount);
+            }
+            commissions.clear();
+            affiliates.swap_remove(&affiliate);
+        }
+
+        let mut escrow = self.refund_escrow(event_id);
+        if escrow.is_empty() {
+            return true;
+        }
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+
+        for (token, balance) in escrow.iter() {
+            if balance.platform_fees > 0 {
+                self.credit_platform_fees(&token, &balance.platform_fees);
+            }
+            if balance.income > 0 {
+                self.credit_income(&token, &balance.income);
+            }
+        }
+        escrow.clear();
+
+        true
+    }
+
+    fn is_refundable_sale(
+        &self,
+        event: &Event<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment,
+    ) -> bool {
+        event.refund_policy
+            && payment.token_nonce == 0
+            && !self.refund_window_closed(&event.id).get()
+    }
+
     #[allow_multiple_var_args]
     fn check_kyc(
         &self,
@@ -196,6 +752,42 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         }
     }
 
+    fn check_receipt(
+        &self,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+        to: &ManagedAddress,
+        quantity: usize,
+        order_id: &ManagedBuffer,
+        amount: &BigUint,
+        currency: &ManagedBuffer,
+        provider: &ManagedAddress,
+        signature: &ManagedBuffer,
+    ) {
+        require!(!order_id.is_empty(), "The order ID is required!");
+        require!(
+            self.payment_providers().contains(provider),
+            "The payment provider is not registered!"
+        );
+
+        // Every field is length prefixed and the tag with this contract's address scopes the receipt
+        let computed = self.serializer().top_encode_to_managed_buffer(&(
+            ManagedBuffer::from(RECEIPT_SIGNATURE_TAG),
+            self.blockchain().get_sc_address(),
+            order_id,
+            amount,
+            currency,
+            to,
+            &event.id,
+            &ticket_type.id,
+            &ticket_stage.id,
+            quantity as u32,
+        ));
+        self.crypto()
+            .verify_ed25519(provider.as_managed_buffer(), &computed, signature);
+    }
+
     fn common_buy_check(
         &self,
         event_id: &ManagedBuffer,
@@ -208,37 +800,74 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         let ticket_type = self.does_ticket_type_exists(event_id, ticket_type_id);
         let ticket_stage = self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
 
-        self.require_is_minting(&ticket_stage);
-        let time_now = self.blockchain().get_block_timestamp();
+        if let Some(error) =
+            self.get_buy_error(caller, quantity, &event, &ticket_type, &ticket_stage)
+        {
+            sc_panic!(error);
+        }
 
-        require!(
-            time_now >= ticket_stage.start_time,
-            "The stage mint starts in the future!"
-        );
+        (event, ticket_type, ticket_stage).into()
+    }
 
-        require!(
-            ticket_stage.end_time == 0 || time_now <= ticket_stage.end_time,
-            "The stage mint has ended!"
-        );
+    fn get_buy_error(
+        &self,
+        caller: &ManagedAddress,
+        quantity: usize,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+    ) -> Option<ManagedBuffer> {
+        if let Some(error) = self.pause_error(&event.id) {
+            return Some(error);
+        }
 
-        if ticket_stage.has_whitelist {
-            require!(
-                self.is_whitelisted(event_id, ticket_type_id, ticket_stage_id, caller),
-                "You are not on the whitelist!"
-            );
+        if let Some(error) = self.event_sale_error(event) {
+            return Some(error);
         }
 
-        require!(
-            quantity > 0,
-            "The quantity {} has to be higer than 0!",
-            quantity
-        );
+        if let Some(error) = self.stage_sale_error(ticket_stage) {
+            return Some(error);
+        }
 
-        self.check_buys_limits(caller, quantity, &event, &ticket_type, &ticket_stage);
+        if ticket_stage.has_whitelist
+            && !self.is_whitelisted(&event.id, &ticket_type.id, &ticket_stage.id, caller)
+        {
+            return Some(ManagedBuffer::from("You are not on the whitelist!"));
+        }
 
-        self.check_sold_out(&event, &ticket_type, &ticket_stage, quantity);
+        if quantity == 0 {
+            return Some(sc_format!(
+                "The quantity {} has to be higer than 0!",
+                quantity
+            ));
+        }
 
-        (event, ticket_type, ticket_stage).into()
+        if let Some(error) =
+            self.buys_limits_error(caller, quantity, event, ticket_type, ticket_stage)
+        {
+            return Some(error);
+        }
+
+        self.sold_out_error(event, ticket_type, ticket_stage, quantity)
+    }
+
+    fn stage_sale_error(&self, ticket_stage: &TicketStage<Self::Api>) -> Option<ManagedBuffer> {
+        if !ticket_stage.active {
+            return Some(ManagedBuffer::from(
+                "The sale is not active yet for this stage!",
+            ));
+        }
+
+        let time_now = self.blockchain().get_block_timestamp();
+        if time_now < ticket_stage.start_time {
+            return Some(ManagedBuffer::from("The stage mint starts in the future!"));
+        }
+
+        if ticket_stage.end_time > 0 && time_now > ticket_stage.end_time {
+            return Some(ManagedBuffer::from("The stage mint has ended!"));
+        }
+
+        None
     }
 
     fn check_buys_limits(
@@ -249,37 +878,67 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         ticket_type: &TicketType<Self::Api>,
         ticket_stage: &TicketStage<Self::Api>,
     ) {
+        if let Some(error) =
+            self.buys_limits_error(caller, quantity, event, ticket_type, ticket_stage)
+        {
+            sc_panic!(error);
+        }
+    }
+
+    fn buys_limits_error(
+        &self,
+        caller: &ManagedAddress,
+        quantity: usize,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+    ) -> Option<ManagedBuffer> {
         if ticket_stage.max_per_user > 0u32 {
             let stage_counts = self
                 .buys_per_ticket_stage(caller, &event.id, &ticket_type.id, &ticket_stage.id)
                 .get();
-            require!(
-                stage_counts + quantity as u32 <= ticket_stage.max_per_user,
-                "Max buys per ticket stage will be over the maximum of {}!",
-                (&ticket_stage.max_per_user)
-            );
+            if stage_counts + quantity as u32 > ticket_stage.max_per_user {
+                return Some(sc_format!(
+                    "Max buys per ticket stage will be over the maximum of {}!",
+                    ticket_stage.max_per_user
+                ));
+            }
         }
 
+        self.type_limits_error(caller, quantity, event, ticket_type)
+    }
+
+    fn type_limits_error(
+        &self,
+        caller: &ManagedAddress,
+        quantity: usize,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+    ) -> Option<ManagedBuffer> {
         if ticket_type.max_per_user > 0u32 {
             let ticket_type_counts = self
                 .buys_per_ticket_type(caller, &event.id, &ticket_type.id)
                 .get();
 
-            require!(
-                ticket_type_counts + quantity as u32 <= ticket_type.max_per_user,
-                "Max buys per stage will be over the maximum of {}!",
-                (&ticket_type.max_per_user)
-            );
+            if ticket_type_counts + quantity as u32 > ticket_type.max_per_user {
+                return Some(sc_format!(
+                    "Max buys per stage will be over the maximum of {}!",
+                    ticket_type.max_per_user
+                ));
+            }
         }
 
         if event.max_per_user > 0u32 {
             let event_counts = self.buys_per_event(caller, &event.id).get();
-            require!(
-                event_counts + quantity as u32 <= event.max_per_user,
-                "Max buys per event will be over the maximum of {}!",
-                (&event.max_per_user)
-            );
+            if event_counts + quantity as u32 > event.max_per_user {
+                return Some(sc_format!(
+                    "Max buys per event will be over the maximum of {}!",
+                    event.max_per_user
+                ));
+            }
         }
+
+        None
     }
 
     fn common_payment_check(
@@ -288,52 +947,48 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         quantity: usize,
         swaps: OptionalValue<ManagedVec<AggregatorStep<Self::Api>>>,
         limits: OptionalValue<ManagedVec<TokenAmount<Self::Api>>>,
-    ) -> MultiValue2<EgldOrEsdtTokenPayment, BigUint> {
+    ) -> MultiValue3<EgldOrEsdtTokenPayment, BigUint, Option<SwapSummary<Self::Api>>> {
         let payment = self.call_value().egld_or_single_esdt();
 
         let has_swap = swaps.is_some() && limits.is_some();
 
         if has_swap {
-            let output = self.aggregate(
+            let back_transfers = self.aggregate(
                 &payment.token_identifier,
-                payment.amount,
+                payment.amount.clone(),
                 swaps.into_option().unwrap(),
                 limits.into_option().unwrap(),
             );
-            let swap_index_price: Option<usize> = ticket_stage.prices.iter().position(|r| {
-                r.token_identifier == output.token_identifier.clone()
-                    && r.token_nonce == output.token_nonce
-            });
-            require!(swap_index_price.is_some(), "Swap invalid!");
-            let price_per_nft = ticket_stage.prices.get(swap_index_price.unwrap()).amount;
+            let (output, price_per_nft) =
+                self.settle_swap_output(ticket_stage, quantity, back_transfers);
             let total_value = BigUint::from(quantity).mul(&price_per_nft);
 
-            require!(
-                &total_value <= &output.amount,
-                "The payment amount is under the total value required for the buy!"
-            );
-            let token = EgldOrEsdtTokenIdentifier::parse(
-                output.token_identifier.as_managed_buffer().clone(),
-            );
-            if &output.amount > &total_value {
+            if output.amount > total_value {
                 self.send().direct(
                     &self.blockchain().get_caller(),
-                    &token,
+                    &output.token_identifier,
                     output.token_nonce,
-                    &(output.amount - &total_value),
+                    &(&output.amount - &total_value),
                 );
             }
             (
-                EgldOrEsdtTokenPayment::new(token, output.token_nonce, total_value),
+                EgldOrEsdtTokenPayment::new(
+                    output.token_identifier.clone(),
+                    output.token_nonce,
+                    total_value,
+                ),
                 price_per_nft,
+                Some(SwapSummary {
+                    input: payment,
+                    output,
+                }),
             )
                 .into()
         } else {
-            let index_price = ticket_stage.prices.iter().position(|r| {
-                r.token_identifier
-                    == TokenIdentifier::from(payment.token_identifier.clone().into_name())
-                    && r.token_nonce == payment.token_nonce
-            });
+            let index_price = ticket_stage
+                .prices
+                .iter()
+                .position(|r| r.matches(&payment.token_identifier, payment.token_nonce));
 
             require!(index_price.is_some(), "Payment invalid!");
             let price_per_nft = ticket_stage.prices.get(index_price.unwrap()).amount;
@@ -342,10 +997,84 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
                 &total_value == &payment.amount,
                 "The payment amount is wrong!"
             );
-            (payment, price_per_nft).into()
+            (payment, price_per_nft, None).into()
         }
     }
 
+    // Keeps the first swap output, summed per token, that covers the whole buy and refunds the rest
+    fn settle_swap_output(
+        &self,
+        ticket_stage: &TicketStage<Self::Api>,
+        quantity: usize,
+        back_transfers: BackTransfers<Self::Api>,
+    ) -> (EgldOrEsdtTokenPayment, BigUint) {
+        let quantity = BigUint::from(quantity);
+        let mut outputs = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        for transfer in back_transfers.esdt_payments.iter() {
+            if transfer.amount == 0 {
+                continue;
+            }
+            match outputs.iter().position(|output| {
+                output.token_identifier == transfer.token_identifier
+                    && output.token_nonce == transfer.token_nonce
+            }) {
+                Some(index) => {
+                    let mut output = outputs.get(index);
+                    output.amount += &transfer.amount;
+                    let _ = outputs.set(index, &output);
+                }
+                None => outputs.push(transfer),
+            }
+        }
+
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        let mut egld_refund = back_transfers.total_egld_amount;
+        let mut matched: Option<(EgldOrEsdtTokenPayment, BigUint)> = None;
+        if egld_refund > 0 {
+            if let Some(price) = ticket_stage.prices.iter().find(|r| r.matches(&egld, 0)) {
+                if &price.amount * &quantity <= egld_refund {
+                    let amount = core::mem::take(&mut egld_refund);
+                    matched = Some((EgldOrEsdtTokenPayment::new(egld, 0, amount), price.amount));
+                }
+            }
+        }
+
+        let mut refunds = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        for output in outputs.iter() {
+            if matched.is_none() {
+                let token = EgldOrEsdtTokenIdentifier::esdt(output.token_identifier.clone());
+                if let Some(price) = ticket_stage
+                    .prices
+                    .iter()
+                    .find(|r| r.matches(&token, output.token_nonce))
+                {
+                    if &price.amount * &quantity <= output.amount {
+                        matched = Some((
+                            EgldOrEsdtTokenPayment::new(token, output.token_nonce, output.amount),
+                            price.amount,
+                        ));
+                        continue;
+                    }
+                }
+            }
+            refunds.push(output);
+        }
+        require!(
+            matched.is_some(),
+            "The payment amount is under the total value required for the buy!"
+        );
+
+        let caller = self.blockchain().get_caller();
+        if !refunds.is_empty() {
+            self.tx().to(&caller).payment(&refunds).transfer();
+        }
+        if egld_refund > 0 {
+            self.tx().to(&caller).egld(&egld_refund).transfer();
+        }
+
+        matched.unwrap()
+    }
+
     fn check_sold_out(
         &self,
         event: &Event<Self::Api>,
@@ -353,22 +1082,39 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         ticket_stage: &TicketStage<Self::Api>,
         count: usize,
     ) {
-        require!(
-            ticket_type.mint_limit == 0
-                || (ticket_type.mint_limit >= ticket_type.mint_count + count as u32),
-            "The ticket type capacity is sold out!"
-        );
+        if let Some(error) = self.sold_out_error(event, ticket_type, ticket_stage, count) {
+            sc_panic!(error);
+        }
+    }
 
-        require!(
-            ticket_stage.mint_limit == 0
-                || (ticket_stage.mint_limit >= ticket_stage.mint_count + count as u32),
-            "The ticket stage capacity is sold out!"
-        );
+    fn sold_out_error(
+        &self,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+        count: usize,
+    ) -> Option<ManagedBuffer> {
+        if ticket_type.mint_limit > 0
+            && ticket_type.mint_limit < ticket_type.mint_count + count as u32
+        {
+            return Some(ManagedBuffer::from("The ticket type capacity is sold out!"));
+        }
 
-        require!(
-            event.max_capacity == 0 || (event.max_capacity >= event.mint_count + count as u32),
-            "The event capacity would be over the maximum!"
-        );
+        if ticket_stage.mint_limit > 0
+            && ticket_stage.mint_limit < ticket_stage.mint_count + count as u32
+        {
+            return Some(ManagedBuffer::from(
+                "The ticket stage capacity is sold out!",
+            ));
+        }
+
+        if event.max_capacity > 0 && event.max_capacity < event.mint_count + count as u32 {
+            return Some(ManagedBuffer::from(
+                "The event capacity would be over the maximum!",
+            ));
+        }
+
+        None
     }
 
     fn check_type_sold_out(
@@ -377,52 +1123,154 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         ticket_type: &TicketType<Self::Api>,
         count: usize,
     ) {
-        require!(
-            ticket_type.mint_limit == 0
-                || (ticket_type.mint_limit >= ticket_type.mint_count + count as u32),
-            "The ticket type capacity is sold out!"
-        );
+        if let Some(error) = self.type_sold_out_error(event, ticket_type, count) {
+            sc_panic!(error);
+        }
+    }
 
-        require!(
-            event.max_capacity == 0 || (event.max_capacity >= event.mint_count + count as u32),
-            "The event capacity would be over the maximum!"
-        );
+    fn type_sold_out_error(
+        &self,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        count: usize,
+    ) -> Option<ManagedBuffer> {
+        if ticket_type.mint_limit > 0
+            && ticket_type.mint_limit < ticket_type.mint_count + count as u32
+        {
+            return Some(ManagedBuffer::from("The ticket type capacity is sold out!"));
+        }
+
+        if event.max_capacity > 0 && event.max_capacity < event.mint_count + count as u32 {
+            return Some(ManagedBuffer::from(
+                "The event capacity would be over the maximum!",
+            ));
+        }
+
+        None
     }
 
-    fn distribute_income(&self, payment: EgldOrEsdtTokenPayment) {
-        if payment.token_nonce > 0 {
-            // self.send().direct(
-            //     &self.local_owner().get(),
-            //     &payment_type,
-            //     payment_nonce,
-            //     &payment_amount,
-            // );
-        } else if payment.amount > 0 {
-            let cut = self.fees().get();
-            let owner_cut = BigUint::from(ROYALTIES_MAX) - &cut;
-            let platform_cut = self.calculate_cut_amount(&payment.amount, &cut);
-            let owner_revenue = self.calculate_cut_amount(&payment.amount, &owner_cut);
-
-            if platform_cut.gt(&BigUint::zero()) {
-                self.tx()
-                    .to(self.blockchain().get_owner_address())
-                    .typed(manager_proxy::ManagerProxy)
-                    .forward_revenue()
-                    .payment(&payment)
-                    .sync_call();
-            }
+    fn distribute_income(
+        &self,
+        event: &Event<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment,
+        price_per_nft: &BigUint,
+        quantity: usize,
+        referral: Option<&ReferralCode<Self::Api>>,
+    ) -> (BigUint, BigUint, BigUint) {
+        let (platform_share, organizer_share, commission_share) =
+            self.split_ticket_price(event, payment, price_per_nft, referral);
+        let platform_cut = BigUint::from(quantity).mul(&platform_share);
+        let owner_revenue = BigUint::from(quantity).mul(&organizer_share);
+        let commission = BigUint::from(quantity).mul(&commission_share);
 
-            if owner_revenue.gt(&BigUint::zero()) {
-                let mut map = self.income();
-                if map.contains_key(&payment.token_identifier) {
-                    let mut data = map.get(&payment.token_identifier).unwrap();
-                    data.amount += payment.amount;
-                    map.insert(payment.token_identifier, data);
-                } else {
-                    map.insert(payment.token_identifier.clone(), payment.clone());
+        let token = &payment.token_identifier;
+        let affiliate = referral.map(|referral| &referral.affiliate);
+        if self.is_refundable_sale(event, payment) {
+            // Refundable revenue stays out of every claimable ledger until the refund window closes
+            if platform_cut > 0 || owner_revenue > 0 {
+                let mut escrow_map = self.refund_escrow(&event.id);
+                let mut escrow = escrow_map.get(token).unwrap_or_else(|| RefundEscrow {
+                    platform_fees: BigUint::zero(),
+                    income: BigUint::zero(),
+                });
+                escrow.platform_fees += &platform_cut;
+                escrow.income += &owner_revenue;
+                escrow_map.insert(token.clone(), escrow);
+            }
+            if let Some(affiliate) = affiliate {
+                if commission > 0 {
+                    let mut commissions = self.escrowed_commissions(&event.id, affiliate);
+                    let total = commissions.get(token).unwrap_or_default() + &commission;
+                    commissions.insert(token.clone(), total);
+                    self.escrowed_affiliates(&event.id)
+                        .insert(affiliate.clone());
+                }
+            }
+        } else {
+            if platform_cut > 0 {
+                self.credit_platform_fees(token, &platform_cut);
+            }
+            if let Some(affiliate) = affiliate {
+                if commission > 0 {
+                    self.credit_affiliate_balance(affiliate, token, &commission);
                 }
             }
+            if owner_revenue > 0 {
+                self.credit_income(token, &owner_revenue);
+            }
         }
+
+        (platform_cut, owner_revenue, commission)
+    }
+
+    fn get_referral(
+        &self,
+        event_id: &ManagedBuffer,
+        code: Option<ManagedBuffer>,
+        buyer: &ManagedAddress,
+    ) -> Option<ReferralCode<Self::Api>> {
+        let code = code?;
+        let map = self.referral_code(event_id, &code);
+        require!(!map.is_empty(), "The referral code {} is invalid!", code);
+
+        let referral = map.get();
+        require!(
+            &referral.affiliate != buyer,
+            "You cannot use your own referral code!"
+        );
+        Some(referral)
+    }
+
+    fn credit_platform_fees(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let mut map = self.platform_fees();
+        let total = map.get(token).unwrap_or_default() + amount;
+        map.insert(token.clone(), total);
+    }
+
+    fn credit_affiliate_balance(
+        &self,
+        affiliate: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let mut map = self.affiliate_balance(affiliate);
+        let total = map.get(token).unwrap_or_default() + amount;
+        map.insert(token.clone(), total);
+    }
+
+    fn credit_income(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let mut map = self.income();
+        let mut data = map
+            .get(token)
+            .unwrap_or_else(|| EgldOrEsdtTokenPayment::new(token.clone(), 0, BigUint::zero()));
+        data.amount += amount;
+        map.insert(token.clone(), data);
+    }
+
+    // Split per ticket so every ticket can be refunded exactly. The platform share and the
+    // affiliate commission, taken from the organizer part, are rounded down and the remainder
+    // goes to the organizer. Payments with a nonce stay out of the ledgers.
+    fn split_ticket_price(
+        &self,
+        event: &Event<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment,
+        price_per_nft: &BigUint,
+        referral: Option<&ReferralCode<Self::Api>>,
+    ) -> (BigUint, BigUint, BigUint) {
+        if payment.token_nonce > 0 || payment.amount == 0 {
+            return (BigUint::zero(), BigUint::zero(), BigUint::zero());
+        }
+
+        let platform_share = self.calculate_cut_amount(price_per_nft, &event.fees);
+        let mut organizer_share = price_per_nft - &platform_share;
+        let commission = match referral {
+            Some(referral) => {
+                self.calculate_cut_amount(&organizer_share, &BigUint::from(referral.commission))
+            }
+            None => BigUint::zero(),
+        };
+        organizer_share -= &commission;
+        (platform_share, organizer_share, commission)
     }
 
     fn get_nft_name(
@@ -438,8 +1286,94 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         }
     }
 
-    fn require_is_minting(&self, stage: &TicketStage<Self::Api>) {
-        require!(stage.active, "The sale is not active yet for this stage!");
+    fn get_nft_attributes(
+        &self,
+        event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        name: &ManagedBuffer,
+        is_check_in: bool,
+    ) -> Attributes<Self::Api> {
+        Attributes {
+            is_check_in,
+            event_id: event.id.clone(),
+            ticket_type_id: ticket_type.id.clone(),
+            name: name.clone(),
+        }
+    }
+
+    fn get_event_schedule(&self, event_id: &ManagedBuffer) -> EventSchedule<Self::Api> {
+        let map = self.event_schedule(event_id);
+        if map.is_empty() {
+            return EventSchedule::default();
+        }
+
+        map.get()
+    }
+
+    fn get_event_status(&self, event: &Event<Self::Api>) -> EventStatus {
+        self.effective_status(&self.get_event_schedule(&event.id))
+    }
+
+    fn effective_status(&self, schedule: &EventSchedule<Self::Api>) -> EventStatus {
+        if schedule.status == EventStatus::Cancelled || schedule.status == EventStatus::Finished {
+            return schedule.status;
+        }
+
+        let time_now = self.blockchain().get_block_timestamp();
+        if schedule.end_time > 0 && time_now > schedule.end_time {
+            EventStatus::Finished
+        } else if schedule.start_time > 0 && time_now >= schedule.start_time {
+            EventStatus::Live
+        } else {
+            schedule.status
+        }
+    }
+
+    fn require_event_on_sale(&self, event: &Event<Self::Api>) {
+        if let Some(error) = self.event_sale_error(event) {
+            sc_panic!(error);
+        }
+    }
+
+    fn event_sale_error(&self, event: &Event<Self::Api>) -> Option<ManagedBuffer> {
+        match self.get_event_status(event) {
+            EventStatus::Cancelled => Some(ManagedBuffer::from("The event has been cancelled!")),
+            EventStatus::Finished => Some(ManagedBuffer::from("The event has ended!")),
+            _ => None,
+        }
+    }
+
+    fn require_check_in_open(&self, event: &Event<Self::Api>) {
+        let schedule = self.get_event_schedule(&event.id);
+        require!(
+            self.effective_status(&schedule) != EventStatus::Cancelled,
+            "The event has been cancelled!"
+        );
+
+        let time_now = self.blockchain().get_block_timestamp();
+        let doors_open = if schedule.doors_open_time > 0 {
+            schedule.doors_open_time
+        } else {
+            schedule.start_time
+        };
+        require!(time_now >= doors_open, "The doors are not open yet!");
+        require!(
+            schedule.end_time == 0 || time_now <= schedule.end_time,
+            "The event has ended!"
+        );
+    }
+
+    fn require_refund_open(&self, event: &Event<Self::Api>) {
+        require!(event.refund_policy, "The event does not allow refunds!");
+        require!(
+            !self.refund_window_closed(&event.id).get(),
+            "The refund window is closed!"
+        );
+        let status = self.get_event_status(event);
+        require!(
+            status == EventStatus::Upcoming || status == EventStatus::Cancelled,
+            "Refunds are only possible before the event starts!"
+        );
     }
 
     fn calculate_cut_amount(&self, total_amount: &BigUint, cut_percentage: &BigUint) -> BigUint {
@@ -452,26 +1386,22 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
         amount: BigUint,
         steps: ManagedVec<AggregatorStep<Self::Api>>,
         limits: ManagedVec<TokenAmount<Self::Api>>,
-    ) -> EsdtTokenPayment<Self::Api> {
+    ) -> BackTransfers<Self::Api> {
         let call = self
             .tx()
             .to(self.get_aggregator())
             .typed(AggregatorContractProxy);
 
         if token.is_esdt() {
-            let all_payments = call
-                .aggregate_esdt(steps, limits, true, OptionalValue::<ManagedAddress>::None)
+            call.aggregate_esdt(steps, limits, true, OptionalValue::<ManagedAddress>::None)
                 .esdt((token.clone().unwrap_esdt(), 0, amount))
                 .returns(ReturnsBackTransfers)
-                .sync_call();
-            all_payments.esdt_payments.get(0)
+                .sync_call()
         } else {
-            let all_payments = call
-                .aggregate_egld(steps, limits, OptionalValue::<ManagedAddress>::None)
+            call.aggregate_egld(steps, limits, OptionalValue::<ManagedAddress>::None)
                 .egld(amount)
                 .returns(ReturnsBackTransfers)
-                .sync_call();
-            all_payments.esdt_payments.get(0)
+                .sync_call()
         }
     }
 
@@ -487,23 +1417,53 @@ pub trait CommonModule: crate::storage::StorageModule + crate::events::EventsMod
             .contains(&address)
     }
 
+    // Contracts deployed before the cache existed fill it on their first read
     fn get_signer(&self) -> ManagedAddress {
-        return self
-            .tx()
+        let map = self.signer();
+        if !map.is_empty() {
+            return map.get();
+        }
+
+        let signer = self.fetch_manager_signer();
+        map.set(&signer);
+        signer
+    }
+
+    fn get_aggregator(&self) -> ManagedAddress {
+        let map = self.aggregator();
+        if !map.is_empty() {
+            return map.get();
+        }
+
+        let aggregator = self.fetch_manager_aggregator();
+        map.set(&aggregator);
+        aggregator
+    }
+
+    fn require_manager(&self) {
+        require!(
+            !self.standalone().get(),
+            "The contract is running without a manager!"
+        );
+    }
+
+    fn fetch_manager_signer(&self) -> ManagedAddress {
+        self.require_manager();
+        self.tx()
             .to(self.blockchain().get_owner_address())
             .typed(manager_proxy::ManagerProxy)
             .signer()
             .returns(ReturnsResult)
-            .sync_call_readonly();
+            .sync_call_readonly()
     }
 
-    fn get_aggregator(&self) -> ManagedAddress {
-        return self
-            .tx()
+    fn fetch_manager_aggregator(&self) -> ManagedAddress {
+        self.require_manager();
+        self.tx()
             .to(self.blockchain().get_owner_address())
             .typed(manager_proxy::ManagerProxy)
             .aggregator_sc()
             .returns(ReturnsResult)
-            .sync_call_readonly();
+            .sync_call_readonly()
     }
 }
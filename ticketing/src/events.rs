@@ -1,4 +1,9 @@
-use crate::structs::{Event, TicketStage, TicketType};
+use crate::structs::{
+    BuyEventData, Event, EventSchedule, EventSummary, MetadataRefreshData, MintEventData,
+    ReceiptEventData, ReferralCode, RefundEventData, Role, SwapSummary, TicketStage, TicketType,
+};
+
+pub const EVENT_SCHEMA_VERSION: u32 = 2;
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -11,6 +16,18 @@ pub trait EventsModule: crate::storage::StorageModule {
     #[event("emit_create_event")]
     fn emit_event(&self, #[indexed] event: &Event<Self::Api>);
 
+    fn emit_event_schedule(&self, event_id: &ManagedBuffer, schedule: &EventSchedule<Self::Api>) {
+        self._emit_event_schedule(EVENT_SCHEMA_VERSION, event_id, schedule);
+    }
+
+    #[event("emit_event_schedule")]
+    fn _emit_event_schedule(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        data: &EventSchedule<Self::Api>,
+    );
+
     fn emit_remove_ticket_stage(
         &self,
         event_id: &ManagedBuffer,
@@ -65,36 +82,309 @@ pub trait EventsModule: crate::storage::StorageModule {
         #[indexed] ticker: TokenIdentifier<Self::Api>,
     );
 
-    #[event("emit_stage_mint_event")]
+    fn emit_metadata_refresh(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        processed: usize,
+        total: usize,
+    ) {
+        self._emit_metadata_refresh_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            ticket_type_id,
+            &MetadataRefreshData {
+                processed: processed as u32,
+                total: total as u32,
+            },
+        );
+    }
+
+    #[event("emit_metadata_refresh_event")]
+    fn _emit_metadata_refresh_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] ticket_type_id: &ManagedBuffer,
+        data: &MetadataRefreshData,
+    );
+
+    fn emit_archive_event(&self, summary: &EventSummary<Self::Api>) {
+        self._emit_archive_event(EVENT_SCHEMA_VERSION, &summary.id, summary);
+    }
+
+    #[event("emit_archive_event")]
+    fn _emit_archive_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        data: &EventSummary<Self::Api>,
+    );
+
+    #[event("emit_role_event")]
+    fn emit_role(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] granted: bool,
+    );
+
+    #[event("emit_contract_pause_event")]
+    fn emit_contract_pause(&self, #[indexed] paused: bool);
+
+    #[event("emit_event_pause_event")]
+    fn emit_event_pause(&self, #[indexed] event_id: &ManagedBuffer, #[indexed] paused: bool);
+
+    #[event("emit_event_fees_event")]
+    fn emit_event_fees(
+        &self,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] old_fees: &BigUint,
+        #[indexed] new_fees: &BigUint,
+    );
+
+    #[event("emit_platform_fees_flush_event")]
+    fn emit_platform_fees_flush(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    fn emit_receipt(
+        &self,
+        event_id: &ManagedBuffer,
+        order_id: &ManagedBuffer,
+        provider: &ManagedAddress,
+        recipient: &ManagedAddress,
+        amount: &BigUint,
+        currency: &ManagedBuffer,
+    ) {
+        self._emit_receipt_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            order_id,
+            &ReceiptEventData {
+                provider: provider.clone(),
+                recipient: recipient.clone(),
+                amount: amount.clone(),
+                currency: currency.clone(),
+            },
+        );
+    }
+
+    #[event("emit_receipt_event")]
+    fn _emit_receipt_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] order_id: &ManagedBuffer,
+        data: &ReceiptEventData<Self::Api>,
+    );
+
+    fn emit_referral(
+        &self,
+        event_id: &ManagedBuffer,
+        referral: &ReferralCode<Self::Api>,
+        buyer: &ManagedAddress,
+        quantity: usize,
+        token: &EgldOrEsdtTokenIdentifier,
+        commission: &BigUint,
+    ) {
+        self._emit_referral_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            &referral.code,
+            &referral.affiliate,
+            buyer,
+            quantity,
+            token,
+            commission,
+            referral,
+        );
+    }
+
+    #[event("emit_referral_event")]
+    fn _emit_referral_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] code: &ManagedBuffer,
+        #[indexed] affiliate: &ManagedAddress,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] quantity: usize,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] commission: &BigUint,
+        data: &ReferralCode<Self::Api>,
+    );
+
+    fn emit_referral_code_registered(
+        &self,
+        event_id: &ManagedBuffer,
+        referral: &ReferralCode<Self::Api>,
+    ) {
+        self._emit_referral_code_registered_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            &referral.code,
+            &referral.affiliate,
+            referral,
+        );
+    }
+
+    #[event("emit_referral_code_registered_event")]
+    fn _emit_referral_code_registered_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] code: &ManagedBuffer,
+        #[indexed] affiliate: &ManagedAddress,
+        data: &ReferralCode<Self::Api>,
+    );
+
+    fn emit_referral_code_removed(
+        &self,
+        event_id: &ManagedBuffer,
+        referral: &ReferralCode<Self::Api>,
+    ) {
+        self._emit_referral_code_removed_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            &referral.code,
+            &referral.affiliate,
+            referral,
+        );
+    }
+
+    #[event("emit_referral_code_removed_event")]
+    fn _emit_referral_code_removed_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] code: &ManagedBuffer,
+        #[indexed] affiliate: &ManagedAddress,
+        data: &ReferralCode<Self::Api>,
+    );
+
+    fn emit_check_in(&self, event_id: &ManagedBuffer, nonces: &ManagedVec<u64>) {
+        self._emit_check_in_event(EVENT_SCHEMA_VERSION, event_id, nonces);
+    }
+
+    #[event("emit_check_in_event")]
+    fn _emit_check_in_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        data: &ManagedVec<u64>,
+    );
+
+    fn emit_refund(
+        &self,
+        event_id: &ManagedBuffer,
+        buyer: &ManagedAddress,
+        ticket: &EsdtTokenPayment,
+        refund: &EgldOrEsdtTokenPayment,
+    ) {
+        self._emit_refund_event(
+            EVENT_SCHEMA_VERSION,
+            event_id,
+            ticket.token_nonce,
+            &RefundEventData {
+                buyer: buyer.clone(),
+                ticket: ticket.clone(),
+                refund: refund.clone(),
+            },
+        );
+    }
+
+    #[event("emit_refund_event")]
+    fn _emit_refund_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] nonce: u64,
+        data: &RefundEventData<Self::Api>,
+    );
+
     fn emit_stage_mint(
         &self,
-        #[indexed] ticket_type: &TicketType<Self::Api>,
-        #[indexed] ticket_stage: &TicketStage<Self::Api>,
-        #[indexed] event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        ticket_stage: &TicketStage<Self::Api>,
+        event: &Event<Self::Api>,
+        is_global_sold_out: bool,
+    ) {
+        self._emit_stage_mint_event(
+            EVENT_SCHEMA_VERSION,
+            &event.id,
+            &ticket_type.id,
+            &ticket_stage.id,
+            ticket_stage.mint_count,
+            is_global_sold_out,
+            &MintEventData {
+                event: event.clone(),
+                ticket_type: ticket_type.clone(),
+                ticket_stage: Some(ticket_stage.clone()),
+            },
+        );
+    }
+
+    #[event("emit_stage_mint_event")]
+    fn _emit_stage_mint_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] ticket_type_id: &ManagedBuffer,
+        #[indexed] ticket_stage_id: &ManagedBuffer,
+        #[indexed] mint_count: u32,
         #[indexed] is_global_sold_out: bool,
+        data: &MintEventData<Self::Api>,
     );
 
-    #[event("emit_type_mint_event")]
     fn emit_type_mint(
         &self,
-        #[indexed] ticket_type: &TicketType<Self::Api>,
-        #[indexed] event: &Event<Self::Api>,
+        ticket_type: &TicketType<Self::Api>,
+        event: &Event<Self::Api>,
+        is_global_sold_out: bool,
+    ) {
+        self._emit_type_mint_event(
+            EVENT_SCHEMA_VERSION,
+            &event.id,
+            &ticket_type.id,
+            ticket_type.mint_count,
+            is_global_sold_out,
+            &MintEventData {
+                event: event.clone(),
+                ticket_type: ticket_type.clone(),
+                ticket_stage: None,
+            },
+        );
+    }
+
+    #[event("emit_type_mint_event")]
+    fn _emit_type_mint_event(
+        &self,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] ticket_type_id: &ManagedBuffer,
+        #[indexed] mint_count: u32,
         #[indexed] is_global_sold_out: bool,
+        data: &MintEventData<Self::Api>,
     );
 
     #[event("emit_buy_event")]
     fn emit_buy_event(
         &self,
-        #[indexed] payments: &ManagedVec<EsdtTokenPayment>,
-        #[indexed] payment_token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] version: u32,
+        #[indexed] event_id: &ManagedBuffer,
+        #[indexed] ticket_type_id: &ManagedBuffer,
         #[indexed] buyer: &ManagedAddress,
+        #[indexed] nonces: &ManagedVec<u64>,
+        #[indexed] payment_token: &EgldOrEsdtTokenIdentifier,
         #[indexed] price: &BigUint,
-        #[indexed] token_identifier: &TokenIdentifier,
-        #[indexed] timestamp: u64,
-        #[indexed] epoch: u64,
+        #[indexed] platform_amount: &BigUint,
+        #[indexed] organizer_amount: &BigUint,
         #[indexed] external_id: &ManagedBuffer,
-        #[indexed] event: &Event<Self::Api>,
-        #[indexed] ticket_type: &TicketType<Self::Api>,
+        data: &BuyEventData<Self::Api>,
     );
 
     fn emit_buy(
@@ -103,23 +393,39 @@ pub trait EventsModule: crate::storage::StorageModule {
         payment_token: &EgldOrEsdtTokenIdentifier,
         buyer: &ManagedAddress,
         price: &BigUint,
+        platform_amount: &BigUint,
+        organizer_amount: &BigUint,
         token_identifier: &TokenIdentifier,
         external_id: &ManagedBuffer,
         event: &Event<Self::Api>,
         ticket_type: &TicketType<Self::Api>,
+        swap: &Option<SwapSummary<Self::Api>>,
     ) {
-        let epoch = self.blockchain().get_block_epoch();
+        let mut nonces = ManagedVec::new();
+        for payment in payments.iter() {
+            nonces.push(payment.token_nonce);
+        }
+
         self.emit_buy_event(
-            payments,
-            payment_token,
+            EVENT_SCHEMA_VERSION,
+            &event.id,
+            &ticket_type.id,
             buyer,
+            &nonces,
+            payment_token,
             price,
-            token_identifier,
-            self.blockchain().get_block_timestamp(),
-            epoch,
+            platform_amount,
+            organizer_amount,
             external_id,
-            event,
-            ticket_type,
+            &BuyEventData {
+                token_identifier: token_identifier.clone(),
+                payments: payments.clone(),
+                timestamp: self.blockchain().get_block_timestamp(),
+                epoch: self.blockchain().get_block_epoch(),
+                event: event.clone(),
+                ticket_type: ticket_type.clone(),
+                swap: swap.clone(),
+            },
         );
     }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    common, events,
+    common, events, pause, roles,
     settings::{self, CallbackProxy},
     storage,
-    structs::{EventArgs, TicketStage, TicketStageArgs, TicketType, TicketTypeArgs},
-    NFT_ISSUE_COST,
+    structs::{
+        EventArgs, EventStatus, MetadataUpdateState, TicketStageArgs, TicketTypeArgs,
+        TicketTypeConfig,
+    },
+    validation, NFT_ISSUE_COST,
 };
 
 multiversx_sc::imports!();
@@ -11,9 +14,14 @@ multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
 pub trait ManageModule:
-    storage::StorageModule + events::EventsModule + common::CommonModule + settings::SettingsModule
+    storage::StorageModule
+    + events::EventsModule
+    + common::CommonModule
+    + settings::SettingsModule
+    + pause::PauseModule
+    + roles::RolesModule
+    + validation::ValidationModule
 {
-    #[only_owner]
     #[payable("EGLD")]
     #[endpoint(createEvent)]
     fn create_event(
@@ -23,13 +31,18 @@ pub trait ManageModule:
         token_ticker: ManagedBuffer,
         args: EventArgs,
     ) {
+        self.require_admin();
         let payment_amount = self.call_value().egld_value();
         require!(
             payment_amount.clone_value() == NFT_ISSUE_COST,
             "Invalid payment amount. Issue costs exactly 0.05 EGLD"
         );
         let mut map = self.events();
-        require!(!map.contains(event_id), "The ID has been used already");
+        require!(
+            !map.contains(event_id) && !self.archived_events().contains(event_id),
+            "The ID has been used already"
+        );
+        self.validate_event_args(event_id, &args, 0);
 
         map.insert(event_id.clone());
 
@@ -45,33 +58,14 @@ pub trait ManageModule:
         );
     }
 
-    #[only_owner]
     #[endpoint(createTicketType)]
     fn create_ticket_type(&self, event_id: &ManagedBuffer, args: &TicketTypeArgs<Self::Api>) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
-
-        let mut map_types = self.ticket_types(event_id);
-        require!(
-            !map_types.contains(&args.id),
-            "This ID has been created already!"
-        );
-        map_types.insert(args.id.clone());
-
-        let ticket_type = TicketType {
-            id: args.id.clone(),
-            base_name: args.base_name.clone(),
-            image: args.image.clone(),
-            royalties: args.royalties.clone(),
-            max_per_user: args.max_per_user,
-            mint_limit: args.mint_limit,
-            mint_count: 0,
-        };
-
-        self.ticket_type_by_id(event_id, &args.id).set(&ticket_type);
-        self.emit_ticket_type(&ticket_type, event_id);
+        self.validate_ticket_type_args(event_id, args, 0);
+        self.insert_ticket_type(event_id, args);
     }
 
-    #[only_owner]
     #[endpoint(createTicketStage)]
     fn create_ticket_stage(
         &self,
@@ -79,39 +73,45 @@ pub trait ManageModule:
         ticket_type_id: &ManagedBuffer,
         args: &TicketStageArgs<Self::Api>,
     ) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
-        self.is_ticket_type_valid(event_id, ticket_type_id);
+        let ticket_type = self.is_ticket_type_valid(event_id, ticket_type_id).get();
+        self.validate_ticket_stage_args(&ticket_type, args, 0);
+        self.insert_ticket_stage(event_id, ticket_type_id, args);
+    }
+
+    #[endpoint(configureEvent)]
+    fn configure_event(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_types: MultiValueEncoded<TicketTypeConfig<Self::Api>>,
+    ) {
+        self.require_organizer(event_id);
+        self.is_event_valid(event_id);
+
+        for config in ticket_types {
+            self.validate_ticket_type_args(event_id, &config.ticket_type, 0);
+            self.insert_ticket_type(event_id, &config.ticket_type);
 
-        let mut map_stages = self.ticket_stages(event_id, ticket_type_id);
-        require!(!map_stages.contains_key(&args.id), "The key already exists");
-        let ticket_stage = TicketStage {
-            id: args.id.clone(),
-            prices: args.prices.clone(),
-            has_whitelist: args.has_whitelist,
-            start_time: args.start_time,
-            end_time: args.end_time,
-            active: args.active,
-            max_per_user: args.max_per_user,
-            mint_limit: args.mint_limit,
-            ticket_type_id: ticket_type_id.clone(),
-            mint_count: 0,
-        };
-        self.emit_ticket_stage(&ticket_stage, event_id);
-        map_stages.insert(args.id.clone(), ticket_stage);
+            let ticket_type = self
+                .ticket_type_by_id(event_id, &config.ticket_type.id)
+                .get();
+            for stage in config.stages.iter() {
+                self.validate_ticket_stage_args(&ticket_type, &stage, 0);
+                self.insert_ticket_stage(event_id, &ticket_type.id, &stage);
+            }
+        }
     }
 
-    #[only_owner]
     #[endpoint(removeTicketType)]
     fn remove_ticket_type(&self, event_id: &ManagedBuffer, ticket_type_id: &ManagedBuffer) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
         self.is_ticket_type_valid(event_id, ticket_type_id);
-        self.ticket_stages(event_id, ticket_type_id).clear();
-        self.ticket_types(event_id).swap_remove(&ticket_type_id);
-        self.ticket_type_by_id(event_id, ticket_type_id).clear();
+        self.detach_ticket_type(event_id, ticket_type_id);
         self.emit_remove_ticket_type(event_id, ticket_type_id);
     }
 
-    #[only_owner]
     #[endpoint(removeTicketStage)]
     fn remove_ticket_stage(
         &self,
@@ -119,22 +119,51 @@ pub trait ManageModule:
         ticket_type_id: &ManagedBuffer,
         ticket_stage_id: &ManagedBuffer,
     ) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
         self.is_ticket_type_valid(event_id, ticket_type_id);
         let mut map = self.ticket_stages(event_id, ticket_type_id);
         let removed_stage = map.remove(ticket_stage_id);
         if removed_stage.is_some() {
+            self.detach_ticket_stage(event_id, ticket_type_id, ticket_stage_id);
             self.emit_remove_ticket_stage(event_id, removed_stage.unwrap());
         }
     }
 
-    #[only_owner]
+    #[endpoint(cleanUpTicketData)]
+    fn clean_up_ticket_data_endpoint(&self, event_id: &ManagedBuffer, batch_size: usize) -> bool {
+        self.require_organizer(event_id);
+        self.is_event_valid(event_id);
+        let mut budget = batch_size;
+        self.clean_up_ticket_data(event_id, &mut budget)
+    }
+
+    #[endpoint(addEventBuyers)]
+    fn add_event_buyers(
+        &self,
+        event_id: &ManagedBuffer,
+        buyers: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_admin();
+        self.is_event_valid(event_id);
+        let mut map = self.event_buyers(event_id);
+        for buyer in buyers {
+            map.insert(buyer);
+        }
+    }
+
     #[endpoint(editTicketType)]
     fn edit_ticket_type(&self, event_id: &ManagedBuffer, args: TicketTypeArgs<Self::Api>) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
 
         let map = self.is_ticket_type_valid(event_id, &args.id);
         let mut old_value = map.get();
+        self.validate_ticket_type_args(event_id, &args, old_value.mint_count);
+
+        if old_value.base_name != args.base_name || old_value.image != args.image {
+            self.metadata_cursor(event_id, &args.id).clear();
+        }
 
         old_value.base_name = args.base_name;
         old_value.image = args.image;
@@ -146,7 +175,88 @@ pub trait ManageModule:
         self.emit_ticket_type(&old_value, event_id);
     }
 
-    #[only_owner]
+    #[endpoint(refreshTicketTypeMetadata)]
+    fn refresh_ticket_type_metadata(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        batch_size: usize,
+    ) -> usize {
+        self.require_organizer(event_id);
+        let event = self.does_event_exists(event_id);
+        let ticket_type = self.does_ticket_type_exists(event_id, ticket_type_id);
+        require!(
+            self.metadata_update_state(event_id).get() == MetadataUpdateState::Enabled,
+            "Metadata updates are not enabled for this event!"
+        );
+        let nonces = self.ticket_type_nonces(event_id, ticket_type_id);
+        let cursor_map = self.metadata_cursor(event_id, ticket_type_id);
+
+        let total = nonces.len();
+        let mut cursor = cursor_map.get();
+        let end = core::cmp::min(cursor + batch_size, total);
+
+        while cursor < end {
+            cursor += 1;
+            let nonce = nonces.get_by_index(cursor);
+            let nft_name = self.get_nft_name(nonce as u32, &ticket_type.base_name, &event);
+            let is_check_in = self.checked_in_tickets(event_id).contains(&nonce);
+            let attributes = self.get_nft_attributes(&event, &ticket_type, &nft_name, is_check_in);
+
+            // Tickets sit in the holders' wallets, so only the dynamic metadata update reaches them
+            self.tx()
+                .to(ToSelf)
+                .gas(GasLeft)
+                .raw_call("ESDTMetaDataUpdate")
+                .argument(&event.token)
+                .argument(&nonce)
+                .argument(&nft_name)
+                .argument(&ticket_type.royalties)
+                .argument(&ManagedBuffer::new())
+                .argument(&attributes)
+                .argument(&ticket_type.image)
+                .sync_call();
+        }
+
+        cursor_map.set(cursor);
+        self.emit_metadata_refresh(event_id, ticket_type_id, cursor, total);
+
+        total - cursor
+    }
+
+    #[endpoint(addTicketTypeNonces)]
+    fn add_ticket_type_nonces(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        nonces: MultiValueEncoded<u64>,
+    ) {
+        self.require_admin();
+        let event = self.does_event_exists(event_id);
+        self.is_ticket_type_valid(event_id, ticket_type_id);
+
+        let last_nonce = self.next_nonce(&event.token).get() as u64;
+        let ticket_types = self.ticket_types(event_id);
+        let mut map = self.ticket_type_nonces(event_id, ticket_type_id);
+        for nonce in nonces {
+            require!(
+                nonce > 0 && nonce < last_nonce,
+                "The ticket {} does not belong to this event!",
+                nonce
+            );
+            for other_type_id in ticket_types.iter() {
+                require!(
+                    !self
+                        .ticket_type_nonces(event_id, &other_type_id)
+                        .contains(&nonce),
+                    "The ticket {} is already tracked!",
+                    nonce
+                );
+            }
+            map.insert(nonce);
+        }
+    }
+
     #[endpoint(editTicketStage)]
     fn edit_ticket_stage(
         &self,
@@ -154,14 +264,16 @@ pub trait ManageModule:
         ticket_type_id: &ManagedBuffer,
         args: TicketStageArgs<Self::Api>,
     ) {
+        self.require_organizer(event_id);
         self.is_event_valid(event_id);
-        self.is_ticket_type_valid(event_id, ticket_type_id);
+        let ticket_type = self.is_ticket_type_valid(event_id, ticket_type_id).get();
         let mut map = self.ticket_stages(event_id, ticket_type_id);
 
         let option_value = map.get(&args.id);
 
         if option_value.is_some() {
             let mut old_value = option_value.unwrap();
+            self.validate_ticket_stage_args(&ticket_type, &args, old_value.mint_count);
             old_value.has_whitelist = args.has_whitelist;
             old_value.max_per_user = args.max_per_user;
             old_value.end_time = args.end_time;
@@ -175,11 +287,12 @@ pub trait ManageModule:
         }
     }
 
-    #[only_owner]
     #[endpoint(editEvent)]
     fn edit_event(&self, event_id: &ManagedBuffer, args: EventArgs) {
+        self.require_organizer(event_id);
         let event_map = self.is_event_valid(event_id);
         let mut event = event_map.get();
+        self.validate_event_args(event_id, &args, event.mint_count);
         event.max_capacity = args.max_capacity;
         event.max_per_user = args.max_per_user;
         event.has_kyc = args.has_kyc;
@@ -190,7 +303,72 @@ pub trait ManageModule:
         event_map.set(event);
     }
 
-    #[only_owner]
+    #[endpoint(setEventSchedule)]
+    fn set_event_schedule(
+        &self,
+        event_id: &ManagedBuffer,
+        venue: ManagedBuffer,
+        start_time: u64,
+        end_time: u64,
+        doors_open_time: u64,
+    ) {
+        self.require_organizer(event_id);
+        self.is_event_valid(event_id);
+        let mut schedule = self.get_event_schedule(event_id);
+        schedule.venue = venue;
+        schedule.start_time = start_time;
+        schedule.end_time = end_time;
+        schedule.doors_open_time = doors_open_time;
+        self.validate_event_schedule(&schedule);
+
+        self.emit_event_schedule(event_id, &schedule);
+        self.event_schedule(event_id).set(schedule);
+    }
+
+    #[endpoint(setEventStatus)]
+    fn set_event_status(&self, event_id: &ManagedBuffer, status: EventStatus) {
+        self.require_organizer(event_id);
+        self.is_event_valid(event_id);
+        let mut schedule = self.get_event_schedule(event_id);
+        let current = self.effective_status(&schedule);
+        let allowed = matches!(
+            (current, status),
+            (EventStatus::Upcoming, EventStatus::Live)
+                | (EventStatus::Upcoming, EventStatus::Cancelled)
+                | (EventStatus::Live, EventStatus::Finished)
+                | (EventStatus::Live, EventStatus::Cancelled)
+        );
+        require!(allowed, "The event status transition is not allowed!");
+
+        schedule.status = status;
+        self.emit_event_schedule(event_id, &schedule);
+        self.event_schedule(event_id).set(schedule);
+    }
+
+    #[endpoint(checkInTickets)]
+    fn check_in_tickets(&self, event_id: &ManagedBuffer, nonces: MultiValueEncoded<u64>) {
+        self.require_scanner(event_id);
+        let event = self.does_event_exists(event_id);
+        self.require_check_in_open(&event);
+
+        let mut map = self.checked_in_tickets(event_id);
+        let mut checked_in = ManagedVec::new();
+        for nonce in nonces {
+            require!(
+                self.is_live_ticket(event_id, nonce),
+                "The ticket {} does not belong to this event!",
+                nonce
+            );
+            require!(
+                map.insert(nonce),
+                "The ticket {} is already checked in!",
+                nonce
+            );
+            checked_in.push(nonce);
+        }
+        self.emit_check_in(event_id, &checked_in);
+    }
+
     #[endpoint(addWhitelists)]
     fn add_to_whitelist(
         &self,
@@ -199,15 +377,16 @@ pub trait ManageModule:
         ticket_stage_id: &ManagedBuffer,
         wallets: MultiValueEncoded<ManagedAddress>,
     ) {
+        self.require_operator(event_id);
         self.is_event_valid(event_id);
         self.is_ticket_type_valid(event_id, ticket_type_id);
+        self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
 
         let mut mapper = self.whitelist_wallets(event_id, ticket_type_id, ticket_stage_id);
         self.emit_whitelist_event(&wallets.to_vec());
         mapper.extend(wallets);
     }
 
-    #[only_owner]
     #[endpoint(removeWhitelists)]
     fn remove_from_whitelist(
         &self,
@@ -216,8 +395,10 @@ pub trait ManageModule:
         ticket_stage_id: &ManagedBuffer,
         wallets: MultiValueEncoded<ManagedAddress>,
     ) {
+        self.require_operator(event_id);
         self.is_event_valid(event_id);
         self.is_ticket_type_valid(event_id, ticket_type_id);
+        self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
 
         let mut mapper = self.whitelist_wallets(event_id, ticket_type_id, ticket_stage_id);
 
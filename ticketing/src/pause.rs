@@ -0,0 +1,61 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait PauseModule: crate::storage::StorageModule + crate::events::EventsModule {
+    #[only_owner]
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.paused().set(true);
+        self.emit_contract_pause(true);
+    }
+
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.paused().clear();
+        self.emit_contract_pause(false);
+    }
+
+    #[only_owner]
+    #[endpoint(pauseEvent)]
+    fn pause_event(&self, event_id: &ManagedBuffer) {
+        require!(
+            !self.event_by_id(event_id).is_empty(),
+            "Your event ID: {} is not valid!",
+            (event_id)
+        );
+        self.event_paused(event_id).set(true);
+        self.emit_event_pause(event_id, true);
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseEvent)]
+    fn unpause_event(&self, event_id: &ManagedBuffer) {
+        require!(
+            !self.event_by_id(event_id).is_empty(),
+            "Your event ID: {} is not valid!",
+            (event_id)
+        );
+        self.event_paused(event_id).clear();
+        self.emit_event_pause(event_id, false);
+    }
+
+    fn require_not_paused(&self, event_id: &ManagedBuffer) {
+        if let Some(error) = self.pause_error(event_id) {
+            sc_panic!(error);
+        }
+    }
+
+    fn pause_error(&self, event_id: &ManagedBuffer) -> Option<ManagedBuffer> {
+        if self.paused().get() {
+            return Some(ManagedBuffer::from("The contract is paused!"));
+        }
+
+        if self.event_paused(event_id).get() {
+            return Some(ManagedBuffer::from("The event is paused!"));
+        }
+
+        None
+    }
+}
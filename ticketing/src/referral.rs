@@ -0,0 +1,99 @@
+use crate::{structs::ReferralCode, ROYALTIES_MAX};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait ReferralModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::common::CommonModule
+    + crate::pause::PauseModule
+    + crate::roles::RolesModule
+{
+    #[endpoint(registerReferralCode)]
+    fn register_referral_code(
+        &self,
+        event_id: &ManagedBuffer,
+        code: &ManagedBuffer,
+        affiliate: ManagedAddress,
+        commission: u32,
+    ) {
+        self.require_organizer(event_id);
+        self.is_event_valid(event_id);
+        require!(!code.is_empty(), "The referral code is required!");
+        require!(
+            commission <= self.max_referral_commission().get(),
+            "The commission is above the allowed maximum!"
+        );
+        require!(
+            self.referral_codes(event_id).insert(code.clone()),
+            "The referral code {} exists already!",
+            code
+        );
+
+        self.affiliate_codes(event_id, &affiliate)
+            .insert(code.clone());
+        let referral = ReferralCode {
+            code: code.clone(),
+            affiliate,
+            commission,
+            orders: 0,
+            tickets: 0,
+        };
+        self.referral_code(event_id, code).set(&referral);
+        self.emit_referral_code_registered(event_id, &referral);
+    }
+
+    #[endpoint(removeReferralCode)]
+    fn remove_referral_code(&self, event_id: &ManagedBuffer, code: &ManagedBuffer) {
+        self.require_organizer(event_id);
+        let map = self.referral_code(event_id, code);
+        require!(!map.is_empty(), "The referral code {} is invalid!", code);
+
+        let referral = map.take();
+        self.affiliate_codes(event_id, &referral.affiliate)
+            .swap_remove(code);
+        self.referral_codes(event_id).swap_remove(code);
+        self.emit_referral_code_removed(event_id, &referral);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxReferralCommission)]
+    fn set_max_referral_commission(&self, commission: u32) {
+        require!(
+            commission <= ROYALTIES_MAX,
+            "Invalid percentage value, should be under 10,000"
+        );
+        self.max_referral_commission().set(commission);
+    }
+
+    #[endpoint(claimAffiliateEarnings)]
+    fn claim_affiliate_earnings(&self) {
+        let caller = self.blockchain().get_caller();
+        let mut balance = self.affiliate_balance(&caller);
+        require!(!balance.is_empty(), "There are no earnings to claim!");
+
+        for (token, amount) in balance.iter() {
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&token, 0, &amount)
+                .transfer();
+        }
+        balance.clear();
+    }
+
+    #[view(getAffiliateSales)]
+    fn get_affiliate_sales(
+        &self,
+        event_id: &ManagedBuffer,
+        affiliate: &ManagedAddress,
+    ) -> ManagedVec<ReferralCode<Self::Api>> {
+        let mut results = ManagedVec::new();
+        for code in self.affiliate_codes(event_id, affiliate).iter() {
+            results.push(self.referral_code(event_id, &code).get());
+        }
+
+        results
+    }
+}
@@ -0,0 +1,130 @@
+use crate::structs::Role;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait RolesModule: crate::storage::StorageModule + crate::events::EventsModule {
+    #[endpoint(grantRole)]
+    fn grant_role(
+        &self,
+        role: Role,
+        address: ManagedAddress,
+        event_id: OptionalValue<ManagedBuffer>,
+    ) {
+        match role {
+            Role::Admin => {
+                self.require_owner();
+                self.admins().insert(address.clone());
+                self.emit_role(role, &address, &ManagedBuffer::new(), true);
+            }
+            _ => {
+                let event_id = self.require_role_event(role, event_id);
+                self.event_role_members(&event_id, role)
+                    .insert(address.clone());
+                self.emit_role(role, &address, &event_id, true);
+            }
+        }
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(
+        &self,
+        role: Role,
+        address: ManagedAddress,
+        event_id: OptionalValue<ManagedBuffer>,
+    ) {
+        match role {
+            Role::Admin => {
+                self.require_owner();
+                self.admins().swap_remove(&address);
+                self.emit_role(role, &address, &ManagedBuffer::new(), false);
+            }
+            _ => {
+                let event_id = self.require_role_event(role, event_id);
+                self.event_role_members(&event_id, role)
+                    .swap_remove(&address);
+                self.emit_role(role, &address, &event_id, false);
+            }
+        }
+    }
+
+    fn require_role_event(
+        &self,
+        role: Role,
+        event_id: OptionalValue<ManagedBuffer>,
+    ) -> ManagedBuffer {
+        require!(event_id.is_some(), "The role requires an event ID!");
+        let event_id = event_id.into_option().unwrap();
+        require!(
+            !self.event_by_id(&event_id).is_empty(),
+            "Your event ID: {} is not valid!",
+            (event_id)
+        );
+
+        if role == Role::Organizer {
+            self.require_admin();
+        } else {
+            self.require_organizer(&event_id);
+        }
+
+        event_id
+    }
+
+    fn require_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address(),
+            "Only the owner can perform this action!"
+        );
+    }
+
+    fn is_admin(&self, address: &ManagedAddress) -> bool {
+        address == &self.blockchain().get_owner_address() || self.admins().contains(address)
+    }
+
+    fn is_organizer(&self, event_id: &ManagedBuffer, address: &ManagedAddress) -> bool {
+        self.is_admin(address)
+            || self
+                .event_role_members(event_id, Role::Organizer)
+                .contains(address)
+    }
+
+    fn require_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_admin(&caller),
+            "Only admins can perform this action!"
+        );
+    }
+
+    fn require_organizer(&self, event_id: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_organizer(event_id, &caller),
+            "Only the event organizers can perform this action!"
+        );
+    }
+
+    fn require_operator(&self, event_id: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_organizer(event_id, &caller)
+                || self
+                    .event_role_members(event_id, Role::Operator)
+                    .contains(&caller),
+            "Only the event sales operators can perform this action!"
+        );
+    }
+
+    fn require_scanner(&self, event_id: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_organizer(event_id, &caller)
+                || self
+                    .event_role_members(event_id, Role::Scanner)
+                    .contains(&caller),
+            "Only the event scanners can perform this action!"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    structs::{Event, EventArgs},
+    manager_proxy,
+    structs::{Event, EventArgs, EventStatus, MetadataUpdateState},
     ROYALTIES_MAX,
 };
 
@@ -8,7 +9,10 @@ multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
 pub trait SettingsModule:
-    crate::storage::StorageModule + crate::common::CommonModule + crate::events::EventsModule
+    crate::storage::StorageModule
+    + crate::common::CommonModule
+    + crate::events::EventsModule
+    + crate::pause::PauseModule
 {
     #[callback]
     fn issue_callback(
@@ -60,6 +64,7 @@ pub trait SettingsModule:
             "The event {} is not having a token!",
             event_id
         );
+        self.require_not_paused(event_id);
 
         let wallet = match address {
             OptionalValue::Some(add) => add,
@@ -119,6 +124,182 @@ pub trait SettingsModule:
         }
     }
 
+    // Metadata updates need a dynamic collection with the update role, one step per call
+    #[only_owner]
+    #[endpoint(enableMetadataUpdates)]
+    fn enable_metadata_updates(&self, event_id: &ManagedBuffer) {
+        let mapper = self.token_manager(event_id);
+        require!(
+            !mapper.is_empty(),
+            "The event {} is not having a token!",
+            event_id
+        );
+
+        let token = mapper.get_token_id();
+        match self.metadata_update_state(event_id).get() {
+            MetadataUpdateState::Disabled => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .raw_call("changeToDynamic")
+                .argument(&token)
+                .callback(
+                    self.callbacks()
+                        .metadata_update_callback(event_id, MetadataUpdateState::Dynamic),
+                )
+                .async_call_and_exit(),
+            MetadataUpdateState::Dynamic => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .raw_call("setSpecialRole")
+                .argument(&token)
+                .argument(&self.blockchain().get_sc_address())
+                .argument(&ManagedBuffer::from("ESDTRoleNFTUpdate"))
+                .callback(
+                    self.callbacks()
+                        .metadata_update_callback(event_id, MetadataUpdateState::Enabled),
+                )
+                .async_call_and_exit(),
+            MetadataUpdateState::Enabled => sc_panic!("Metadata updates are already enabled!"),
+        }
+    }
+
+    #[callback]
+    fn metadata_update_callback(
+        &self,
+        event_id: &ManagedBuffer,
+        state: MetadataUpdateState,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(_) = result {
+            self.metadata_update_state(event_id).set(state);
+        }
+    }
+
+    #[endpoint(flushPlatformFees)]
+    fn flush_platform_fees(&self, token: OptionalValue<EgldOrEsdtTokenIdentifier>) {
+        let mut map = self.platform_fees();
+        let tokens: ManagedVec<EgldOrEsdtTokenIdentifier> = match token {
+            OptionalValue::Some(token) => ManagedVec::from_single_item(token),
+            OptionalValue::None => map.keys().collect(),
+        };
+
+        let standalone = self.standalone().get();
+        let manager = self.blockchain().get_owner_address();
+        let same_shard = self.blockchain().get_shard_of_address(&manager)
+            == self
+                .blockchain()
+                .get_shard_of_address(&self.blockchain().get_sc_address());
+
+        for token in tokens.iter() {
+            let amount = match map.remove(&token) {
+                Some(amount) if amount > 0 => amount,
+                _ => continue,
+            };
+
+            if standalone {
+                self.tx()
+                    .to(self.fee_recipient().get())
+                    .egld_or_single_esdt(&token, 0, &amount)
+                    .transfer();
+                self.emit_platform_fees_flush(&token, &amount);
+            } else if same_shard {
+                self.tx()
+                    .to(&manager)
+                    .typed(manager_proxy::ManagerProxy)
+                    .forward_revenue()
+                    .egld_or_single_esdt(&token, 0, &amount)
+                    .sync_call();
+                self.emit_platform_fees_flush(&token, &amount);
+            } else {
+                // Cross shard only one transfer can be made per call, the rest stays in the ledger
+                self.tx()
+                    .to(&manager)
+                    .typed(manager_proxy::ManagerProxy)
+                    .forward_revenue()
+                    .egld_or_single_esdt(&token, 0, &amount)
+                    .callback(
+                        self.callbacks()
+                            .flush_platform_fees_callback(&token, &amount),
+                    )
+                    .async_call_and_exit()
+            }
+        }
+    }
+
+    #[callback]
+    fn flush_platform_fees_callback(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.emit_platform_fees_flush(token, amount);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.credit_platform_fees(token, amount);
+            }
+        }
+    }
+
+    // Once refunds are no longer possible the escrowed revenue becomes claimable
+    #[endpoint(releaseRefundEscrow)]
+    fn release_refund_escrow_endpoint(&self, event_id: &ManagedBuffer, batch_size: usize) -> bool {
+        let event = self.does_event_exists(event_id);
+        let status = self.get_event_status(&event);
+        require!(
+            !event.refund_policy || status == EventStatus::Live || status == EventStatus::Finished,
+            "The refund window is still open!"
+        );
+
+        let mut budget = batch_size;
+        self.release_refund_escrow(event_id, &mut budget)
+    }
+
+    #[only_owner]
+    #[allow_multiple_var_args]
+    #[endpoint(refreshManagerConfig)]
+    fn refresh_manager_config(
+        &self,
+        signer: OptionalValue<ManagedAddress>,
+        aggregator: OptionalValue<ManagedAddress>,
+    ) {
+        let signer = match signer {
+            OptionalValue::Some(signer) => signer,
+            OptionalValue::None => self.fetch_manager_signer(),
+        };
+        let aggregator = match aggregator {
+            OptionalValue::Some(aggregator) => aggregator,
+            OptionalValue::None => self.fetch_manager_aggregator(),
+        };
+
+        self.signer().set(&signer);
+        self.aggregator().set(&aggregator);
+    }
+
+    #[only_owner]
+    #[endpoint(addPaymentProvider)]
+    fn add_payment_provider(&self, provider: ManagedAddress) {
+        self.payment_providers().insert(provider);
+    }
+
+    #[only_owner]
+    #[endpoint(removePaymentProvider)]
+    fn remove_payment_provider(&self, provider: ManagedAddress) {
+        self.payment_providers().swap_remove(&provider);
+    }
+
+    #[only_owner]
+    #[endpoint(setFeeRecipient)]
+    fn set_fee_recipient(&self, address: ManagedAddress) {
+        require!(
+            self.standalone().get(),
+            "The fee recipient is only used without a manager!"
+        );
+        self.fee_recipient().set(&address);
+    }
+
     #[only_owner]
     #[endpoint(setFees)]
     fn set_cut_fees(&self, fees: BigUint) {
@@ -128,4 +309,20 @@ pub trait SettingsModule:
         );
         self.fees().set(&fees);
     }
+
+    #[only_owner]
+    #[endpoint(setEventFees)]
+    fn set_event_fees(&self, event_id: &ManagedBuffer, fees: BigUint) {
+        require!(
+            fees < ROYALTIES_MAX,
+            "Invalid percentage value, should be under 10,000"
+        );
+        let event_map = self.is_event_valid(event_id);
+        let mut event = event_map.get();
+        let old_fees = event.fees.clone();
+        event.fees = fees;
+        event_map.set(&event);
+
+        self.emit_event_fees(event_id, &old_fees, &event.fees);
+    }
 }
@@ -1,4 +1,8 @@
-use crate::structs::{Event, TicketStage, TicketType};
+use crate::structs::{
+    AirdropFailure, AirdropJob, AirdropRecipient, Event, EventSchedule, EventSummary,
+    MetadataUpdateState, PendingCleanup, ReferralCode, RefundEscrow, Role, StageRevenue,
+    StageStats, TicketRecord, TicketStage, TicketType,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -13,6 +17,12 @@ pub trait StorageModule {
     #[view(getEvent)]
     #[storage_mapper("event")]
     fn event_by_id(&self, event_id: &ManagedBuffer) -> SingleValueMapper<Event<Self::Api>>;
+
+    #[storage_mapper("eventSchedule")]
+    fn event_schedule(
+        &self,
+        event_id: &ManagedBuffer,
+    ) -> SingleValueMapper<EventSchedule<Self::Api>>;
     // EVENT //
 
     // TICKET TYPES //
@@ -90,11 +100,232 @@ pub trait StorageModule {
     #[storage_mapper("token")]
     fn token_manager(&self, event_id: &ManagedBuffer) -> NonFungibleTokenMapper<Self::Api>;
 
+    #[view(getTicketTypeNonces)]
+    #[storage_mapper("ticketTypeNonces")]
+    fn ticket_type_nonces(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+    ) -> UnorderedSetMapper<u64>;
+
+    #[view(getCompedTickets)]
+    #[storage_mapper("compedTickets")]
+    fn comped_tickets(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+    ) -> SingleValueMapper<u32>;
+
+    #[view(getMetadataCursor)]
+    #[storage_mapper("metadataCursor")]
+    fn metadata_cursor(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getMetadataUpdateState)]
+    #[storage_mapper("metadataUpdateState")]
+    fn metadata_update_state(
+        &self,
+        event_id: &ManagedBuffer,
+    ) -> SingleValueMapper<MetadataUpdateState>;
+
     #[view(getTransferWallets)]
     #[storage_mapper("transferWallets")]
     fn transfer_wallets(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
     // COLLECTION MANAGEMENT //
 
+    // TICKET HOLDERS //
+    #[view(getEventBuyers)]
+    #[storage_mapper("eventBuyers")]
+    fn event_buyers(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTicketRecord)]
+    #[storage_mapper("ticketRecord")]
+    fn ticket_record(
+        &self,
+        event_id: &ManagedBuffer,
+        nonce: u64,
+    ) -> SingleValueMapper<TicketRecord<Self::Api>>;
+
+    #[view(getCheckedInTickets)]
+    #[storage_mapper("checkedInTickets")]
+    fn checked_in_tickets(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<u64>;
+
+    #[view(getExternalOrders)]
+    #[storage_mapper("externalOrders")]
+    fn external_orders(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getExternalOrderTickets)]
+    #[storage_mapper("externalOrderTickets")]
+    fn external_order_tickets(
+        &self,
+        event_id: &ManagedBuffer,
+        external_id: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+
+    #[view(getRefundEscrow)]
+    #[storage_mapper("refundEscrow")]
+    fn refund_escrow(
+        &self,
+        event_id: &ManagedBuffer,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, RefundEscrow<Self::Api>>;
+
+    #[view(getEscrowedAffiliates)]
+    #[storage_mapper("escrowedAffiliates")]
+    fn escrowed_affiliates(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getEscrowedCommissions)]
+    #[storage_mapper("escrowedCommissions")]
+    fn escrowed_commissions(
+        &self,
+        event_id: &ManagedBuffer,
+        affiliate: &ManagedAddress,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(isRefundWindowClosed)]
+    #[storage_mapper("refundWindowClosed")]
+    fn refund_window_closed(&self, event_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+    // TICKET HOLDERS //
+
+    // ARCHIVE //
+    #[view(getArchivedEvents)]
+    #[storage_mapper("archivedEvents")]
+    fn archived_events(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getEventSummary)]
+    #[storage_mapper("eventSummary")]
+    fn event_summary(&self, event_id: &ManagedBuffer)
+        -> SingleValueMapper<EventSummary<Self::Api>>;
+
+    #[storage_mapper("archiveCursor")]
+    fn archive_cursor(&self, event_id: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(getPendingCleanups)]
+    #[storage_mapper("pendingCleanups")]
+    fn pending_cleanups(&self, event_id: &ManagedBuffer) -> VecMapper<PendingCleanup<Self::Api>>;
+
+    #[storage_mapper("cleanupCursor")]
+    fn cleanup_cursor(&self, event_id: &ManagedBuffer) -> SingleValueMapper<usize>;
+    // ARCHIVE //
+
+    // REFERRALS //
+    #[view(getReferralCodes)]
+    #[storage_mapper("referralCodes")]
+    fn referral_codes(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getReferralCode)]
+    #[storage_mapper("referralCode")]
+    fn referral_code(
+        &self,
+        event_id: &ManagedBuffer,
+        code: &ManagedBuffer,
+    ) -> SingleValueMapper<ReferralCode<Self::Api>>;
+
+    #[storage_mapper("affiliateCodes")]
+    fn affiliate_codes(
+        &self,
+        event_id: &ManagedBuffer,
+        affiliate: &ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getAffiliateBalance)]
+    #[storage_mapper("affiliateBalance")]
+    fn affiliate_balance(
+        &self,
+        affiliate: &ManagedAddress,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    // Tickets of a referred order still held, keyed by the first nonce of the order
+    #[storage_mapper("referralOrder")]
+    fn referral_order(&self, event_id: &ManagedBuffer, order_nonce: u64) -> SingleValueMapper<u32>;
+
+    #[view(getMaxReferralCommission)]
+    #[storage_mapper("maxReferralCommission")]
+    fn max_referral_commission(&self) -> SingleValueMapper<u32>;
+    // REFERRALS //
+
+    // ANALYTICS //
+    #[storage_mapper("stageStats")]
+    fn stage_stats(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) -> SingleValueMapper<StageStats>;
+
+    #[storage_mapper("stageRevenue")]
+    fn stage_revenue(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, StageRevenue<Self::Api>>;
+
+    #[storage_mapper("stageBuyer")]
+    fn stage_buyer(
+        &self,
+        buyer: &ManagedAddress,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) -> SingleValueMapper<bool>;
+    // ANALYTICS //
+
+    // AIRDROP //
+    #[view(getAirdropJobs)]
+    #[storage_mapper("airdropJobs")]
+    fn airdrop_jobs(&self, event_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getAirdropProgress)]
+    #[storage_mapper("airdropJob")]
+    fn airdrop_job(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<AirdropJob<Self::Api>>;
+
+    #[storage_mapper("airdropRecipients")]
+    fn airdrop_recipients(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+    ) -> VecMapper<AirdropRecipient<Self::Api>>;
+
+    #[view(getAirdropFailures)]
+    #[storage_mapper("airdropFailures")]
+    fn airdrop_failures(
+        &self,
+        event_id: &ManagedBuffer,
+        job_id: &ManagedBuffer,
+    ) -> VecMapper<AirdropFailure<Self::Api>>;
+    // AIRDROP //
+
+    // ROLES //
+    #[view(getAdmins)]
+    #[storage_mapper("admins")]
+    fn admins(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getEventRoleMembers)]
+    #[storage_mapper("eventRoleMembers")]
+    fn event_role_members(
+        &self,
+        event_id: &ManagedBuffer,
+        role: Role,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+    // ROLES //
+
+    // PAUSE //
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[view(isEventPaused)]
+    #[storage_mapper("eventPaused")]
+    fn event_paused(&self, event_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+    // PAUSE //
+
     // TICKETING MANAGEMENT //
     #[view(getFees)]
     #[storage_mapper("fees")]
@@ -103,5 +334,29 @@ pub trait StorageModule {
     #[view(getIncome)]
     #[storage_mapper("income")]
     fn income(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment>;
-     // TICKETING MANAGEMENT //
+
+    #[view(getPlatformFees)]
+    #[storage_mapper("platformFees")]
+    fn platform_fees(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(getSigner)]
+    #[storage_mapper("signer")]
+    fn signer(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAggregator)]
+    #[storage_mapper("aggregator")]
+    fn aggregator(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPaymentProviders)]
+    #[storage_mapper("paymentProviders")]
+    fn payment_providers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(isStandalone)]
+    #[storage_mapper("standalone")]
+    fn standalone(&self) -> SingleValueMapper<bool>;
+
+    #[view(getFeeRecipient)]
+    #[storage_mapper("feeRecipient")]
+    fn fee_recipient(&self) -> SingleValueMapper<ManagedAddress>;
+    // TICKETING MANAGEMENT //
 }
@@ -1,6 +1,34 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq,
+)]
+pub enum EventStatus {
+    Upcoming,
+    Live,
+    Finished,
+    Cancelled,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataUpdateState {
+    Disabled,
+    Dynamic,
+    Enabled,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Admin,
+    Organizer,
+    Operator,
+    Scanner,
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct Event<M: ManagedTypeApi> {
@@ -28,11 +56,43 @@ pub struct EventArgs {
     pub bot_protection: bool,
 }
 
+// Kept apart from Event so events stored before the schedule existed still decode
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSchedule<M: ManagedTypeApi> {
+    pub venue: ManagedBuffer<M>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub doors_open_time: u64,
+    pub status: EventStatus,
+}
+
+impl<M: ManagedTypeApi> Default for EventSchedule<M> {
+    fn default() -> Self {
+        EventSchedule {
+            venue: ManagedBuffer::new(),
+            start_time: 0,
+            end_time: 0,
+            doors_open_time: 0,
+            status: EventStatus::Upcoming,
+        }
+    }
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventDetails<M: ManagedTypeApi> {
+    pub event: Event<M>,
+    pub schedule: EventSchedule<M>,
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct Attributes<M: ManagedTypeApi> {
     pub is_check_in: bool,
     pub event_id: ManagedBuffer<M>,
+    pub ticket_type_id: ManagedBuffer<M>,
+    pub name: ManagedBuffer<M>,
 }
 
 #[type_abi]
@@ -48,7 +108,7 @@ pub struct TicketType<M: ManagedTypeApi> {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketTypeArgs<M: ManagedTypeApi> {
     pub base_name: ManagedBuffer<M>,
     pub image: ManagedBuffer<M>,
@@ -58,10 +118,29 @@ pub struct TicketTypeArgs<M: ManagedTypeApi> {
     pub mint_limit: u32,
 }
 
+// Same layout as EgldOrEsdtTokenPayment, which cannot be stored in a ManagedVec
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StagePrice<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> StagePrice<M> {
+    pub fn matches(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier<M>,
+        token_nonce: u64,
+    ) -> bool {
+        &self.token_identifier == token_identifier && self.token_nonce == token_nonce
+    }
+}
+
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketStage<M: ManagedTypeApi> {
-    pub prices: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub prices: ManagedVec<M, StagePrice<M>>,
     pub id: ManagedBuffer<M>,
     pub ticket_type_id: ManagedBuffer<M>,
     pub has_whitelist: bool,
@@ -76,7 +155,7 @@ pub struct TicketStage<M: ManagedTypeApi> {
 #[type_abi]
 #[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TicketStageArgs<M: ManagedTypeApi> {
-    pub prices: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub prices: ManagedVec<M, StagePrice<M>>,
     pub id: ManagedBuffer<M>,
     pub has_whitelist: bool,
     pub max_per_user: u32,
@@ -86,4 +165,243 @@ pub struct TicketStageArgs<M: ManagedTypeApi> {
     pub active: bool,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketRecord<M: ManagedTypeApi> {
+    pub buyer: ManagedAddress<M>,
+    pub ticket_type_id: ManagedBuffer<M>,
+    pub ticket_stage_id: ManagedBuffer<M>,
+    pub price: EgldOrEsdtTokenPayment<M>,
+    pub platform_share: BigUint<M>,
+    pub organizer_share: BigUint<M>,
+    pub affiliate: Option<ManagedAddress<M>>,
+    pub commission: BigUint<M>,
+    pub referral_code: Option<ManagedBuffer<M>>,
+    pub order_nonce: u64,
+}
+
+// Revenue of refundable tickets, held per event and token until the refund window closes
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RefundEscrow<M: ManagedTypeApi> {
+    pub platform_fees: BigUint<M>,
+    pub income: BigUint<M>,
+}
+
+// An empty stage ID stands for the ticket type itself
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PendingCleanup<M: ManagedTypeApi> {
+    pub ticket_type_id: ManagedBuffer<M>,
+    pub ticket_stage_id: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AirdropJob<M: ManagedTypeApi> {
+    pub id: ManagedBuffer<M>,
+    pub event_id: ManagedBuffer<M>,
+    pub ticket_type_id: ManagedBuffer<M>,
+    pub ticket_stage_id: ManagedBuffer<M>,
+    pub respect_limits: bool,
+    pub total: u32,
+    pub processed: u32,
+    pub minted: u32,
+    pub failed: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AirdropRecipient<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub quantity: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AirdropFailure<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub quantity: u32,
+    pub reason: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketTypeConfig<M: ManagedTypeApi> {
+    pub ticket_type: TicketTypeArgs<M>,
+    pub stages: ManagedVec<M, TicketStageArgs<M>>,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq,
+)]
+pub enum StageTimeStatus {
+    NotStarted,
+    Ongoing,
+    Ended,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketStageSnapshot<M: ManagedTypeApi> {
+    pub stage: TicketStage<M>,
+    pub time_status: StageTimeStatus,
+    pub remaining: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TicketTypeSnapshot<M: ManagedTypeApi> {
+    pub ticket_type: TicketType<M>,
+    pub remaining: u32,
+    pub stages: ManagedVec<M, TicketStageSnapshot<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSnapshot<M: ManagedTypeApi> {
+    pub event: Event<M>,
+    pub schedule: EventSchedule<M>,
+    pub token: TokenIdentifier<M>,
+    pub remaining: u32,
+    pub ticket_types: ManagedVec<M, TicketTypeSnapshot<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BuyEligibility<M: ManagedTypeApi> {
+    pub eligible: bool,
+    pub reason: ManagedBuffer<M>,
+    pub stage_remaining: u32,
+    pub type_remaining: u32,
+    pub event_remaining: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BuyEventData<M: ManagedTypeApi> {
+    pub token_identifier: TokenIdentifier<M>,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub timestamp: u64,
+    pub epoch: u64,
+    pub event: Event<M>,
+    pub ticket_type: TicketType<M>,
+    pub swap: Option<SwapSummary<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MintEventData<M: ManagedTypeApi> {
+    pub event: Event<M>,
+    pub ticket_type: TicketType<M>,
+    pub ticket_stage: Option<TicketStage<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SwapSummary<M: ManagedTypeApi> {
+    pub input: EgldOrEsdtTokenPayment<M>,
+    pub output: EgldOrEsdtTokenPayment<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ReceiptEventData<M: ManagedTypeApi> {
+    pub provider: ManagedAddress<M>,
+    pub recipient: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub currency: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RefundEventData<M: ManagedTypeApi> {
+    pub buyer: ManagedAddress<M>,
+    pub ticket: EsdtTokenPayment<M>,
+    pub refund: EgldOrEsdtTokenPayment<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MetadataRefreshData {
+    pub processed: u32,
+    pub total: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ReferralCode<M: ManagedTypeApi> {
+    pub code: ManagedBuffer<M>,
+    pub affiliate: ManagedAddress<M>,
+    pub commission: u32,
+    pub orders: u32,
+    pub tickets: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct StageStats {
+    pub sold: u32,
+    pub comps: u32,
+    pub refunds: u32,
+    pub unique_buyers: u32,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StageRevenue<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub gross_revenue: BigUint<M>,
+    pub platform_fees: BigUint<M>,
+    pub net_revenue: BigUint<M>,
+    pub refunded: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> StageRevenue<M> {
+    pub fn new(token_identifier: EgldOrEsdtTokenIdentifier<M>) -> Self {
+        StageRevenue {
+            token_identifier,
+            gross_revenue: BigUint::zero(),
+            platform_fees: BigUint::zero(),
+            net_revenue: BigUint::zero(),
+            refunded: BigUint::zero(),
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StageReport<M: ManagedTypeApi> {
+    pub ticket_type_id: ManagedBuffer<M>,
+    pub ticket_stage_id: ManagedBuffer<M>,
+    pub mint_count: u32,
+    pub stats: StageStats,
+    pub revenue: ManagedVec<M, StageRevenue<M>>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PriceQuote<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub price_per_ticket: BigUint<M>,
+    pub total_amount: BigUint<M>,
+    pub min_swap_token: EgldOrEsdtTokenIdentifier<M>,
+    pub min_swap_output: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EventSummary<M: ManagedTypeApi> {
+    pub id: ManagedBuffer<M>,
+    pub token: TokenIdentifier<M>,
+    pub status: EventStatus,
+    pub mint_count: u32,
+    pub max_capacity: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub archived_at: u64,
+}
+
 pub type PaymentsVec<M> = ManagedVec<M, EsdtTokenPayment<M>>;
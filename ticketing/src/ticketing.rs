@@ -8,15 +8,22 @@ use multiversx_sc::imports::*;
 const NFT_ISSUE_COST: u64 = 50_000_000_000_000_000; // 0.05 EGLD
 const ROYALTIES_MAX: u32 = 10_000;
 const NFT_AMOUNT: u32 = 1;
+const RECEIPT_SIGNATURE_TAG: &[u8] = b"ticketing_receipt";
 
 pub mod aggregator;
+pub mod airdrop;
+pub mod archive;
 pub mod common;
 pub mod events;
 pub mod manage;
 pub mod manager_proxy;
+pub mod pause;
+pub mod referral;
+pub mod roles;
 pub mod settings;
 pub mod storage;
 pub mod structs;
+pub mod validation;
 pub mod views;
 
 #[multiversx_sc::contract]
@@ -27,10 +34,28 @@ pub trait Ticketing:
     + common::CommonModule
     + views::ViewsModule
     + manage::ManageModule
+    + pause::PauseModule
+    + roles::RolesModule
+    + archive::ArchiveModule
+    + validation::ValidationModule
+    + airdrop::AirdropModule
+    + referral::ReferralModule
 {
     #[init]
-    fn init(&self, fees: BigUint) {
+    fn init(
+        &self,
+        fees: BigUint,
+        standalone: OptionalValue<MultiValue3<ManagedAddress, ManagedAddress, ManagedAddress>>,
+    ) {
         self.set_cut_fees(fees);
+
+        if let OptionalValue::Some(config) = standalone {
+            let (signer, aggregator, fee_recipient) = config.into_tuple();
+            self.standalone().set(true);
+            self.signer().set(&signer);
+            self.aggregator().set(&aggregator);
+            self.fee_recipient().set(&fee_recipient);
+        }
     }
 
     #[upgrade]
@@ -49,14 +74,68 @@ pub trait Ticketing:
         data: OptionalValue<ManagedBuffer>,
         swaps: OptionalValue<ManagedVec<AggregatorStep<Self::Api>>>,
         limits: OptionalValue<ManagedVec<TokenAmount<Self::Api>>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.buy_ticket(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            quantity,
+            None,
+            signature,
+            data,
+            swaps,
+            limits,
+        )
+    }
+
+    #[allow_multiple_var_args]
+    #[endpoint(buyTicketWithReferral)]
+    #[payable("*")]
+    fn buy_with_referral(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+        referral_code: ManagedBuffer,
+        signature: OptionalValue<ManagedBuffer>,
+        data: OptionalValue<ManagedBuffer>,
+        swaps: OptionalValue<ManagedVec<AggregatorStep<Self::Api>>>,
+        limits: OptionalValue<ManagedVec<TokenAmount<Self::Api>>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.buy_ticket(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            quantity,
+            Some(referral_code),
+            signature,
+            data,
+            swaps,
+            limits,
+        )
+    }
+
+    fn buy_ticket(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+        referral_code: Option<ManagedBuffer>,
+        signature: OptionalValue<ManagedBuffer>,
+        data: OptionalValue<ManagedBuffer>,
+        swaps: OptionalValue<ManagedVec<AggregatorStep<Self::Api>>>,
+        limits: OptionalValue<ManagedVec<TokenAmount<Self::Api>>>,
     ) -> ManagedVec<EsdtTokenPayment> {
         let caller = self.blockchain().get_caller();
+        let referral = self.get_referral(event_id, referral_code, &caller);
 
         let (mut event, mut ticket_type, mut ticket_stage) = self
             .common_buy_check(event_id, ticket_type_id, ticket_stage_id, quantity, &caller)
             .into_tuple();
 
-        let (payment, price_per_nft) = self
+        let (payment, price_per_nft, swap) = self
             .common_payment_check(&ticket_stage, quantity, swaps, limits)
             .into_tuple();
 
@@ -78,22 +157,202 @@ pub trait Ticketing:
             quantity,
         );
 
+        self.record_tickets(
+            &event,
+            &ticket_type,
+            &ticket_stage,
+            &caller,
+            &payments,
+            &payment,
+            &price_per_nft,
+            referral.as_ref(),
+        );
+
+        let payment_token = payment.token_identifier.clone();
+        let (platform_amount, organizer_amount, commission) = self.distribute_income(
+            &event,
+            &payment,
+            &price_per_nft,
+            quantity,
+            referral.as_ref(),
+        );
+        if let Some(mut referral) = referral {
+            referral.orders += 1;
+            referral.tickets += quantity as u32;
+            self.referral_code(event_id, &referral.code).set(&referral);
+            self.emit_referral(
+                event_id,
+                &referral,
+                &caller,
+                quantity,
+                &payment_token,
+                &commission,
+            );
+        }
+        self.record_stage_sale(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            &caller,
+            quantity,
+            &payment,
+            &platform_amount,
+            &organizer_amount,
+        );
         self.emit_buy(
             &payments,
-            &payment.token_identifier,
+            &payment_token,
             &caller,
             &price_per_nft,
+            &platform_amount,
+            &organizer_amount,
             &event.token,
             &ManagedBuffer::new(),
             &event,
             &ticket_type,
+            &swap,
+        );
+        payments
+    }
+
+    #[allow_multiple_var_args]
+    #[endpoint(buyWithReceipt)]
+    fn buy_with_receipt(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+        to: &ManagedAddress,
+        order_id: &ManagedBuffer,
+        amount: BigUint,
+        currency: ManagedBuffer,
+        provider: ManagedAddress,
+        receipt_signature: ManagedBuffer,
+        signature: OptionalValue<ManagedBuffer>,
+        data: OptionalValue<ManagedBuffer>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        if let Some(payments) = self.get_external_order(event_id, order_id) {
+            return payments;
+        }
+
+        let (mut event, mut ticket_type, mut ticket_stage) = self
+            .common_buy_check(event_id, ticket_type_id, ticket_stage_id, quantity, to)
+            .into_tuple();
+
+        self.check_receipt(
+            &event,
+            &ticket_type,
+            &ticket_stage,
+            to,
+            quantity,
+            order_id,
+            &amount,
+            &currency,
+            &provider,
+            &receipt_signature,
+        );
+        self.check_kyc(
+            &event,
+            &ticket_type,
+            &ticket_stage,
+            to,
+            quantity,
+            signature,
+            data,
+        );
+
+        let payments = self.send_nft(
+            &mut event,
+            &mut ticket_type,
+            Option::Some(&mut ticket_stage),
+            to,
+            quantity,
+        );
+
+        self.record_stage_sale(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            to,
+            quantity,
+            &EgldOrEsdtTokenPayment::no_payment(),
+            &BigUint::zero(),
+            &BigUint::zero(),
+        );
+        self.emit_receipt(event_id, order_id, &provider, to, &amount, &currency);
+        self.emit_buy(
+            &payments,
+            &EgldOrEsdtTokenIdentifier::egld(),
+            to,
+            &BigUint::zero(),
+            &BigUint::zero(),
+            &BigUint::zero(),
+            &event.token,
+            order_id,
+            &event,
+            &ticket_type,
+            &None,
         );
-        self.distribute_income(payment);
+
+        self.save_external_order(event_id, order_id, &payments);
         payments
     }
 
+    #[payable("*")]
+    #[endpoint(refundTicket)]
+    fn refund(&self, event_id: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.call_value().egld_value().clone_value() == 0,
+            "Only tickets can be sent for a refund!"
+        );
+        let tickets = self.call_value().all_esdt_transfers().clone_value();
+        require!(!tickets.is_empty(), "No tickets were sent for a refund!");
+        let mut event = self.does_event_exists(event_id);
+        self.require_not_paused(event_id);
+        self.require_refund_open(&event);
+
+        for ticket in tickets.iter() {
+            require!(
+                ticket.token_identifier == event.token,
+                "The ticket does not belong to this event!"
+            );
+            require!(
+                !self
+                    .checked_in_tickets(event_id)
+                    .contains(&ticket.token_nonce),
+                "The ticket {} is already checked in!",
+                (ticket.token_nonce)
+            );
+            let record_map = self.ticket_record(event_id, ticket.token_nonce);
+            require!(
+                !record_map.is_empty(),
+                "The ticket {} is not refundable!",
+                (ticket.token_nonce)
+            );
+            let record = record_map.take();
+
+            self.release_ticket(&mut event, &record);
+            self.remove_ticket_nonce(event_id, &record.ticket_type_id, ticket.token_nonce);
+            let refund = self.reverse_ticket_income(event_id, &record);
+            self.reverse_referral_sale(event_id, &record);
+            self.record_stage_refund(event_id, &record, &refund.amount);
+            self.send().esdt_local_burn(
+                &ticket.token_identifier,
+                ticket.token_nonce,
+                &ticket.amount,
+            );
+            if refund.amount > 0 {
+                self.tx().to(&caller).payment(&refund).transfer();
+            }
+            self.emit_refund(event_id, &caller, &ticket, &refund);
+        }
+
+        self.event_by_id(event_id).set(event);
+    }
+
     #[endpoint(issueFreeTicket)]
-    #[only_owner]
     fn giveaway(
         &self,
         event_id: &ManagedBuffer,
@@ -101,32 +360,115 @@ pub trait Ticketing:
         external_id: &ManagedBuffer,
         users: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) -> ManagedVec<EsdtTokenPayment> {
+        self.issue_free_tickets(
+            event_id,
+            ticket_type_id,
+            &ManagedBuffer::new(),
+            false,
+            external_id,
+            users,
+        )
+    }
+
+    #[endpoint(issueFreeStageTicket)]
+    fn giveaway_stage(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        respect_limits: bool,
+        external_id: &ManagedBuffer,
+        users: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.issue_free_tickets(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            respect_limits,
+            external_id,
+            users,
+        )
+    }
+
+    fn issue_free_tickets(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        respect_limits: bool,
+        external_id: &ManagedBuffer,
+        users: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        self.require_operator(event_id);
+        if let Some(payments) = self.get_external_order(event_id, external_id) {
+            return payments;
+        }
+
         let mut event = self.does_event_exists(event_id);
         let mut ticket_type = self.does_ticket_type_exists(event_id, ticket_type_id);
+        let mut ticket_stage = if ticket_stage_id.is_empty() {
+            None
+        } else {
+            Some(self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id))
+        };
+        self.require_not_paused(event_id);
+        self.require_event_on_sale(&event);
         let mut all_payments = ManagedVec::new();
         for user in users {
             let (to, quantity) = user.into_tuple();
-            self.check_type_sold_out(&event, &ticket_type, quantity);
-            let payments = self.send_nft(&mut event, &mut ticket_type, Option::None, &to, quantity);
+            match &ticket_stage {
+                Some(stage) => {
+                    if respect_limits {
+                        self.check_buys_limits(&to, quantity, &event, &ticket_type, stage);
+                    }
+                    self.check_sold_out(&event, &ticket_type, stage, quantity);
+                }
+                None => {
+                    if respect_limits {
+                        if let Some(error) =
+                            self.type_limits_error(&to, quantity, &event, &ticket_type)
+                        {
+                            sc_panic!(error);
+                        }
+                    }
+                    self.check_type_sold_out(&event, &ticket_type, quantity);
+                }
+            }
+            let payments = self.send_nft(
+                &mut event,
+                &mut ticket_type,
+                ticket_stage.as_mut(),
+                &to,
+                quantity,
+            );
+            self.comped_tickets(event_id, ticket_type_id)
+                .update(|count| *count += quantity as u32);
+            if !ticket_stage_id.is_empty() {
+                self.record_stage_comps(event_id, ticket_type_id, ticket_stage_id, quantity);
+            }
 
             self.emit_buy(
                 &payments,
                 &EgldOrEsdtTokenIdentifier::egld(),
                 &to,
                 &BigUint::zero(),
+                &BigUint::zero(),
+                &BigUint::zero(),
                 &event.token,
                 external_id,
                 &event,
                 &ticket_type,
+                &None,
             );
 
             all_payments.append_vec(payments);
         }
+
+        self.save_external_order(event_id, external_id, &all_payments);
         all_payments
     }
 
     #[allow_multiple_var_args]
-    #[only_owner]
     #[endpoint(issuePaidTicket)]
     fn giveaway_admin(
         &self,
@@ -139,6 +481,11 @@ pub trait Ticketing:
         signature: OptionalValue<ManagedBuffer>,
         data: OptionalValue<ManagedBuffer>,
     ) -> ManagedVec<EsdtTokenPayment> {
+        self.require_operator(event_id);
+        if let Some(payments) = self.get_external_order(event_id, external_id) {
+            return payments;
+        }
+
         let (mut event, mut ticket_type, mut ticket_stage) = self
             .common_buy_check(event_id, ticket_type_id, ticket_stage_id, quantity, to)
             .into_tuple();
@@ -160,17 +507,32 @@ pub trait Ticketing:
             to,
             quantity,
         );
+        self.record_stage_sale(
+            event_id,
+            ticket_type_id,
+            ticket_stage_id,
+            to,
+            quantity,
+            &EgldOrEsdtTokenPayment::no_payment(),
+            &BigUint::zero(),
+            &BigUint::zero(),
+        );
 
         self.emit_buy(
             &payments,
             &EgldOrEsdtTokenIdentifier::egld(),
             to,
             &BigUint::zero(),
+            &BigUint::zero(),
+            &BigUint::zero(),
             &event.token,
             external_id,
             &event,
             &ticket_type,
+            &None,
         );
+
+        self.save_external_order(event_id, external_id, &payments);
         payments
     }
 }
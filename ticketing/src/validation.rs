@@ -0,0 +1,145 @@
+use crate::{
+    structs::{EventArgs, EventSchedule, TicketStageArgs, TicketType, TicketTypeArgs},
+    ROYALTIES_MAX,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait ValidationModule: crate::storage::StorageModule {
+    fn validate_event_schedule(&self, schedule: &EventSchedule<Self::Api>) {
+        require!(
+            schedule.end_time == 0 || schedule.start_time <= schedule.end_time,
+            "The event start time must be before its end time!"
+        );
+        require!(
+            schedule.doors_open_time == 0
+                || schedule.start_time == 0
+                || schedule.doors_open_time <= schedule.start_time,
+            "The doors must open before the event starts!"
+        );
+    }
+
+    fn validate_event_args(&self, event_id: &ManagedBuffer, args: &EventArgs, mint_count: u32) {
+        require!(
+            args.max_capacity == 0 || args.max_capacity >= mint_count,
+            "The event capacity cannot be lower than the {} tickets already minted!",
+            mint_count
+        );
+        require!(
+            args.max_capacity == 0 || args.max_per_user <= args.max_capacity,
+            "The event max per user cannot exceed the event capacity!"
+        );
+
+        if args.max_capacity > 0 {
+            for ticket_type_id in self.ticket_types(event_id).iter() {
+                let ticket_type = self.ticket_type_by_id(event_id, &ticket_type_id).get();
+                require!(
+                    ticket_type.mint_limit <= args.max_capacity,
+                    "The ticket type {} mint limit exceeds the event capacity!",
+                    ticket_type_id
+                );
+            }
+        }
+
+        if args.max_per_user > 0 {
+            for ticket_type_id in self.ticket_types(event_id).iter() {
+                let ticket_type = self.ticket_type_by_id(event_id, &ticket_type_id).get();
+                require!(
+                    ticket_type.max_per_user <= args.max_per_user,
+                    "The ticket type {} max per user exceeds the event max per user!",
+                    ticket_type_id
+                );
+            }
+        }
+    }
+
+    fn validate_ticket_type_args(
+        &self,
+        event_id: &ManagedBuffer,
+        args: &TicketTypeArgs<Self::Api>,
+        mint_count: u32,
+    ) {
+        let event = self.event_by_id(event_id).get();
+
+        require!(
+            args.royalties <= ROYALTIES_MAX,
+            "The royalties cannot exceed {}!",
+            ROYALTIES_MAX
+        );
+        require!(
+            args.mint_limit == 0 || args.mint_limit >= mint_count,
+            "The ticket type mint limit cannot be lower than the {} tickets already minted!",
+            mint_count
+        );
+        require!(
+            event.max_capacity == 0 || args.mint_limit <= event.max_capacity,
+            "The ticket type mint limit cannot exceed the event capacity!"
+        );
+        require!(
+            event.max_per_user == 0 || args.max_per_user <= event.max_per_user,
+            "The ticket type max per user cannot exceed the event max per user!"
+        );
+
+        if args.mint_limit > 0 {
+            for ticket_stage in self.ticket_stages(event_id, &args.id).values() {
+                require!(
+                    ticket_stage.mint_limit <= args.mint_limit,
+                    "The ticket stage {} mint limit exceeds the ticket type limit!",
+                    (ticket_stage.id)
+                );
+            }
+        }
+
+        if args.max_per_user > 0 {
+            for ticket_stage in self.ticket_stages(event_id, &args.id).values() {
+                require!(
+                    ticket_stage.max_per_user <= args.max_per_user,
+                    "The ticket stage {} max per user exceeds the ticket type max per user!",
+                    (ticket_stage.id)
+                );
+            }
+        }
+    }
+
+    fn validate_ticket_stage_args(
+        &self,
+        ticket_type: &TicketType<Self::Api>,
+        args: &TicketStageArgs<Self::Api>,
+        mint_count: u32,
+    ) {
+        require!(
+            args.end_time == 0 || args.start_time <= args.end_time,
+            "The stage start time must be before its end time!"
+        );
+        require!(
+            args.mint_limit == 0 || args.mint_limit >= mint_count,
+            "The ticket stage mint limit cannot be lower than the {} tickets already minted!",
+            mint_count
+        );
+        require!(
+            ticket_type.mint_limit == 0 || args.mint_limit <= ticket_type.mint_limit,
+            "The ticket stage mint limit cannot exceed the ticket type limit!"
+        );
+        require!(
+            ticket_type.max_per_user == 0 || args.max_per_user <= ticket_type.max_per_user,
+            "The ticket stage max per user cannot exceed the ticket type max per user!"
+        );
+        require!(
+            !args.prices.is_empty(),
+            "The ticket stage needs at least one price!"
+        );
+
+        for (index, price) in args.prices.iter().enumerate() {
+            for other in args.prices.iter().skip(index + 1) {
+                require!(
+                    price.token_identifier != other.token_identifier
+                        || price.token_nonce != other.token_nonce,
+                    "The price token {} is listed more than once!",
+                    (price.token_identifier)
+                );
+            }
+        }
+    }
+}
@@ -1,24 +1,51 @@
-use crate::structs::{Event, TicketStage, TicketType};
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::structs::{
+    BuyEligibility, EventDetails, EventSchedule, EventSnapshot, EventStatus, PriceQuote,
+    StageReport, StageTimeStatus, TicketStage, TicketStageSnapshot, TicketType, TicketTypeSnapshot,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
 pub trait ViewsModule:
-    crate::storage::StorageModule + crate::events::EventsModule + crate::common::CommonModule
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::common::CommonModule
+    + crate::pause::PauseModule
 {
+    #[view(getEventSchemaVersion)]
+    fn get_event_schema_version(&self) -> u32 {
+        EVENT_SCHEMA_VERSION
+    }
+
     #[view(getEvents)]
-    fn get_events(&self) -> ManagedVec<Event<Self::Api>> {
+    fn get_events(&self) -> ManagedVec<EventDetails<Self::Api>> {
         let mut results = ManagedVec::new();
         let events = self.events();
 
         for event_id in events.iter() {
-            results.push(self.does_event_exists(&event_id));
+            results.push(self.get_event_details(&event_id));
         }
 
         return results;
     }
 
+    #[view(getEventSchedule)]
+    fn get_event_schedule_view(&self, event_id: &ManagedBuffer) -> EventSchedule<Self::Api> {
+        self.is_event_valid(event_id);
+        let mut schedule = self.get_event_schedule(event_id);
+        schedule.status = self.effective_status(&schedule);
+        schedule
+    }
+
+    fn get_event_details(&self, event_id: &ManagedBuffer) -> EventDetails<Self::Api> {
+        let event = self.does_event_exists(event_id);
+        let mut schedule = self.get_event_schedule(event_id);
+        schedule.status = self.effective_status(&schedule);
+        EventDetails { event, schedule }
+    }
+
     #[view(getTypes)]
     fn get_types(&self, event_id: &ManagedBuffer) -> ManagedVec<TicketType<Self::Api>> {
         let mut results = ManagedVec::new();
@@ -63,6 +90,333 @@ pub trait ViewsModule:
         results
     }
 
+    #[view(getEventSnapshot)]
+    fn get_event_snapshot(&self, event_id: &ManagedBuffer) -> EventSnapshot<Self::Api> {
+        let EventDetails { event, schedule } = self.get_event_details(event_id);
+        let event_remaining = self.remaining_capacity(event.max_capacity, event.mint_count);
+        let time_now = self.blockchain().get_block_timestamp();
+
+        let mut ticket_types = ManagedVec::new();
+        for ticket_type_id in self.ticket_types(event_id).iter() {
+            let ticket_type = self.ticket_type_by_id(event_id, &ticket_type_id).get();
+            let type_remaining = core::cmp::min(
+                event_remaining,
+                self.remaining_capacity(ticket_type.mint_limit, ticket_type.mint_count),
+            );
+
+            let mut stages = ManagedVec::new();
+            for stage in self.ticket_stages(event_id, &ticket_type_id).values() {
+                let time_status = if time_now < stage.start_time {
+                    StageTimeStatus::NotStarted
+                } else if stage.end_time > 0 && time_now > stage.end_time {
+                    StageTimeStatus::Ended
+                } else {
+                    StageTimeStatus::Ongoing
+                };
+                let remaining = core::cmp::min(
+                    type_remaining,
+                    self.remaining_capacity(stage.mint_limit, stage.mint_count),
+                );
+                stages.push(TicketStageSnapshot {
+                    stage,
+                    time_status,
+                    remaining,
+                });
+            }
+
+            ticket_types.push(TicketTypeSnapshot {
+                ticket_type,
+                remaining: type_remaining,
+                stages,
+            });
+        }
+
+        EventSnapshot {
+            token: self.token_manager(event_id).get_token_id(),
+            event,
+            schedule,
+            remaining: event_remaining,
+            ticket_types,
+        }
+    }
+
+    #[view(canBuy)]
+    fn can_buy(
+        &self,
+        address: &ManagedAddress,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+    ) -> BuyEligibility<Self::Api> {
+        let mut result = BuyEligibility {
+            eligible: false,
+            reason: ManagedBuffer::new(),
+            stage_remaining: 0,
+            type_remaining: 0,
+            event_remaining: 0,
+        };
+
+        let event_map = self.event_by_id(event_id);
+        if event_map.is_empty() {
+            result.reason = ManagedBuffer::from("The event is invalid!");
+            return result;
+        }
+
+        let type_map = self.ticket_type_by_id(event_id, ticket_type_id);
+        if type_map.is_empty() {
+            result.reason = ManagedBuffer::from("The ticket type is invalid!");
+            return result;
+        }
+
+        let stage_opt = self
+            .ticket_stages(event_id, ticket_type_id)
+            .get(ticket_stage_id);
+        if stage_opt.is_none() {
+            result.reason = sc_format!("Your ticket stage ID: {} is not valid!", ticket_stage_id);
+            return result;
+        }
+
+        let event = event_map.get();
+        let ticket_type = type_map.get();
+        let ticket_stage = stage_opt.unwrap();
+
+        result.event_remaining = core::cmp::min(
+            self.remaining_capacity(event.max_capacity, event.mint_count),
+            self.remaining_capacity(
+                event.max_per_user,
+                self.buys_per_event(address, event_id).get(),
+            ),
+        );
+        result.type_remaining = core::cmp::min(
+            result.event_remaining,
+            core::cmp::min(
+                self.remaining_capacity(ticket_type.mint_limit, ticket_type.mint_count),
+                self.remaining_capacity(
+                    ticket_type.max_per_user,
+                    self.buys_per_ticket_type(address, event_id, ticket_type_id)
+                        .get(),
+                ),
+            ),
+        );
+        result.stage_remaining = core::cmp::min(
+            result.type_remaining,
+            core::cmp::min(
+                self.remaining_capacity(ticket_stage.mint_limit, ticket_stage.mint_count),
+                self.remaining_capacity(
+                    ticket_stage.max_per_user,
+                    self.buys_per_ticket_stage(address, event_id, ticket_type_id, ticket_stage_id)
+                        .get(),
+                ),
+            ),
+        );
+
+        match self.get_buy_error(address, quantity, &event, &ticket_type, &ticket_stage) {
+            Some(error) => result.reason = error,
+            None => result.eligible = true,
+        }
+
+        result
+    }
+
+    #[view(getQuote)]
+    fn get_quote(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        quantity: usize,
+        token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+    ) -> ManagedVec<PriceQuote<Self::Api>> {
+        let ticket_stage = self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
+        let token = token.into_option();
+        let mut results = ManagedVec::new();
+
+        for price in ticket_stage.prices.iter() {
+            if token.is_some() && token.as_ref() != Some(&price.token_identifier) {
+                continue;
+            }
+
+            let total_amount = BigUint::from(quantity).mul(&price.amount);
+            results.push(PriceQuote {
+                min_swap_token: price.token_identifier.clone(),
+                min_swap_output: total_amount.clone(),
+                token_identifier: price.token_identifier.clone(),
+                token_nonce: price.token_nonce,
+                price_per_ticket: price.amount.clone(),
+                total_amount,
+            });
+        }
+
+        results
+    }
+
+    #[view(getStageReport)]
+    fn get_stage_report(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+    ) -> StageReport<Self::Api> {
+        let ticket_stage = self.is_ticket_stage_valid(event_id, ticket_type_id, ticket_stage_id);
+        let mut revenue = ManagedVec::new();
+        for item in self
+            .stage_revenue(event_id, ticket_type_id, ticket_stage_id)
+            .values()
+        {
+            revenue.push(item);
+        }
+
+        StageReport {
+            ticket_type_id: ticket_type_id.clone(),
+            ticket_stage_id: ticket_stage_id.clone(),
+            mint_count: ticket_stage.mint_count,
+            stats: self.get_stage_stats(event_id, ticket_type_id, ticket_stage_id),
+            revenue,
+        }
+    }
+
+    #[view(getEventsPage)]
+    fn get_events_page(
+        &self,
+        from: usize,
+        size: usize,
+        status: OptionalValue<EventStatus>,
+    ) -> ManagedVec<EventDetails<Self::Api>> {
+        let mut results = ManagedVec::new();
+        let status = status.into_option();
+        let events = self.events();
+
+        // Without a filter the page is a plain index range, skipped events are never decoded
+        if status.is_none() {
+            for index in self.page_range(events.len(), from, size) {
+                results.push(self.get_event_details(&events.get_by_index(index)));
+            }
+            return results;
+        }
+
+        let mut matched = 0;
+        for event_id in events.iter() {
+            if results.len() >= size {
+                break;
+            }
+
+            let details = self.get_event_details(&event_id);
+            if status.is_some() && status != Some(details.schedule.status) {
+                continue;
+            }
+
+            matched += 1;
+            if matched > from {
+                results.push(details);
+            }
+        }
+
+        results
+    }
+
+    #[view(getTypesPage)]
+    fn get_types_page(
+        &self,
+        event_id: &ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> ManagedVec<TicketType<Self::Api>> {
+        let mut results = ManagedVec::new();
+        let ticket_types = self.ticket_types(event_id);
+
+        for index in self.page_range(ticket_types.len(), from, size) {
+            let ticket_type_id = ticket_types.get_by_index(index);
+            results.push(self.ticket_type_by_id(event_id, &ticket_type_id).get());
+        }
+
+        results
+    }
+
+    #[view(getAllStagesPage)]
+    fn get_all_stages_page(
+        &self,
+        event_id: &ManagedBuffer,
+        from: usize,
+        size: usize,
+        active_only: bool,
+    ) -> ManagedVec<TicketStage<Self::Api>> {
+        let mut results = ManagedVec::new();
+
+        // Without a filter whole ticket types are skipped by their stage count
+        if !active_only {
+            let mut skip = from;
+            for ticket_type_id in self.ticket_types(event_id).iter() {
+                let stages = self.ticket_stages(event_id, &ticket_type_id);
+                if skip >= stages.len() {
+                    skip -= stages.len();
+                    continue;
+                }
+
+                for ticket_stage_id in stages.keys().skip(skip) {
+                    if results.len() >= size {
+                        return results;
+                    }
+                    results.push(stages.get(&ticket_stage_id).unwrap());
+                }
+                skip = 0;
+            }
+            return results;
+        }
+
+        let mut matched = 0;
+        for ticket_type_id in self.ticket_types(event_id).iter() {
+            for ticket_stage in self.ticket_stages(event_id, &ticket_type_id).values() {
+                if results.len() >= size {
+                    return results;
+                }
+
+                if active_only && self.stage_sale_error(&ticket_stage).is_some() {
+                    continue;
+                }
+
+                matched += 1;
+                if matched > from {
+                    results.push(ticket_stage);
+                }
+            }
+        }
+
+        results
+    }
+
+    #[view(getAllowedUsersPage)]
+    fn get_allowed_users_page(
+        &self,
+        event_id: &ManagedBuffer,
+        ticket_type_id: &ManagedBuffer,
+        ticket_stage_id: &ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> ManagedVec<ManagedAddress> {
+        let mut results = ManagedVec::new();
+        let wallets = self.whitelist_wallets(event_id, ticket_type_id, ticket_stage_id);
+
+        for index in self.page_range(wallets.len(), from, size) {
+            results.push(wallets.get_by_index(index));
+        }
+
+        results
+    }
+
+    fn remaining_capacity(&self, limit: u32, count: u32) -> u32 {
+        if limit == 0 {
+            u32::MAX
+        } else {
+            limit.saturating_sub(count)
+        }
+    }
+
+    fn page_range(&self, len: usize, from: usize, size: usize) -> core::ops::RangeInclusive<usize> {
+        let end = core::cmp::min(from.saturating_add(size), len);
+        from.saturating_add(1)..=end
+    }
+
     #[view(getAllIncomeTokens)]
     fn get_all_income_tokens(&self) -> ManagedVec<EgldOrEsdtTokenIdentifier> {
         return self.income().keys().collect();
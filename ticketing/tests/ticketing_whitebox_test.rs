@@ -0,0 +1,1473 @@
+use ed25519_dalek::{Signer, SigningKey};
+use multiversx_sc_scenario::imports::*;
+
+use ticketing::airdrop::AirdropModule;
+use ticketing::archive::ArchiveModule;
+use ticketing::common::CommonModule;
+use ticketing::manage::ManageModule;
+use ticketing::pause::PauseModule;
+use ticketing::referral::ReferralModule;
+use ticketing::roles::RolesModule;
+use ticketing::settings::SettingsModule;
+use ticketing::storage::StorageModule;
+use ticketing::structs::{
+    Attributes, Event, EventArgs, EventStatus, MetadataUpdateState, Role, StagePrice, TicketStage,
+    TicketStageArgs, TicketTypeArgs,
+};
+use ticketing::views::ViewsModule;
+use ticketing::Ticketing;
+
+const CODE_PATH: MxscPath = MxscPath::new("output/ticketing.mxsc.json");
+const MOCK_AGGREGATOR_PATH: MxscPath = MxscPath::new("output/mock-aggregator.mxsc.json");
+const OWNER: TestAddress = TestAddress::new("owner");
+const BUYER: TestAddress = TestAddress::new("buyer");
+const SIGNER: TestAddress = TestAddress::new("signer");
+const AGGREGATOR: TestAddress = TestAddress::new("aggregator");
+const FEE_RECIPIENT: TestAddress = TestAddress::new("fee-recipient");
+const AFFILIATE: TestAddress = TestAddress::new("affiliate");
+const ORGANIZER: TestAddress = TestAddress::new("organizer");
+const OPERATOR: TestAddress = TestAddress::new("operator");
+const TICKETING: TestSCAddress = TestSCAddress::new("ticketing");
+const MOCK_AGGREGATOR: TestSCAddress = TestSCAddress::new("mock-aggregator");
+const TICKET_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("TICKET-123456");
+const INPUT_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+const WEGLD_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-123456");
+const MEX_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("MEX-123456");
+
+const EVENT_ID: &str = "event";
+const TICKET_TYPE_ID: &str = "general";
+const TICKET_STAGE_ID: &str = "presale";
+const REFERRAL_CODE: &str = "friend";
+const COMMISSION: u32 = 1_000;
+const MAX_COMMISSION: u32 = 2_000;
+const FEES: u64 = 500;
+const PRICE: u64 = 1_019;
+const BUYER_BALANCE: u64 = 1_000_000;
+const PROVIDER_KEY: [u8; 32] = [7u8; 32];
+
+// Pays back whatever outputs the test configured, in as many transfers as configured
+mod mock_aggregator {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait MockAggregator {
+        #[init]
+        fn init(&self) {}
+
+        #[payable("EGLD")]
+        #[endpoint(aggregateEgld)]
+        fn aggregate_egld(&self, _args: MultiValueEncoded<ManagedBuffer>) {
+            self.pay_outputs();
+        }
+
+        #[payable("*")]
+        #[endpoint(aggregateEsdt)]
+        fn aggregate_esdt(&self, _args: MultiValueEncoded<ManagedBuffer>) {
+            self.pay_outputs();
+        }
+
+        fn pay_outputs(&self) {
+            let caller = self.blockchain().get_caller();
+            for output in self.esdt_outputs().iter() {
+                self.tx().to(&caller).payment(output).transfer();
+            }
+            let egld = self.egld_output().get();
+            if egld > 0 {
+                self.tx().to(&caller).egld(egld).transfer();
+            }
+        }
+
+        #[storage_mapper("esdtOutputs")]
+        fn esdt_outputs(&self) -> VecMapper<EsdtTokenPayment>;
+
+        #[storage_mapper("egldOutput")]
+        fn egld_output(&self) -> SingleValueMapper<BigUint>;
+    }
+}
+
+use mock_aggregator::MockAggregator;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE_PATH, ticketing::ContractBuilder);
+    world.register_contract(MOCK_AGGREGATOR_PATH, mock_aggregator::ContractBuilder);
+    world
+}
+
+fn setup(refund_policy: bool) -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(BUYER).nonce(1).balance(BUYER_BALANCE);
+    world.account(FEE_RECIPIENT).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .new_address(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.init(
+                BigUint::from(FEES),
+                OptionalValue::Some(
+                    (
+                        SIGNER.to_managed_address(),
+                        AGGREGATOR.to_managed_address(),
+                        FEE_RECIPIENT.to_managed_address(),
+                    )
+                        .into(),
+                ),
+            );
+        });
+    world.set_esdt_local_roles(
+        TICKETING,
+        TICKET_TOKEN.as_bytes(),
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let token = TICKET_TOKEN.to_token_identifier();
+            sc.events().insert(event_id.clone());
+            sc.next_nonce(&token).set(1u32);
+            sc.token_manager(&event_id).set_token_id(token.clone());
+            sc.event_by_id(&event_id).set(Event {
+                token,
+                transfer_role: false,
+                id: event_id.clone(),
+                max_capacity: 0,
+                max_per_user: 0,
+                fees: BigUint::from(FEES),
+                mint_count: 0,
+                has_kyc: false,
+                refund_policy,
+                append_number: true,
+                bot_protection: false,
+            });
+            sc.insert_ticket_type(&event_id, &ticket_type_args(0));
+            sc.insert_ticket_stage(
+                &event_id,
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ticket_stage_args(0),
+            );
+        });
+
+    world
+}
+
+fn ticket_type_args(max_per_user: u32) -> TicketTypeArgs<DebugApi> {
+    TicketTypeArgs {
+        base_name: ManagedBuffer::from("General"),
+        image: ManagedBuffer::from("https://tickets/general.png"),
+        royalties: BigUint::zero(),
+        id: ManagedBuffer::from(TICKET_TYPE_ID),
+        max_per_user,
+        mint_limit: 0,
+    }
+}
+
+fn ticket_stage_args(max_per_user: u32) -> TicketStageArgs<DebugApi> {
+    TicketStageArgs {
+        prices: ManagedVec::from_single_item(StagePrice {
+            token_identifier: EgldOrEsdtTokenIdentifier::egld(),
+            token_nonce: 0,
+            amount: BigUint::from(PRICE),
+        }),
+        id: ManagedBuffer::from(TICKET_STAGE_ID),
+        has_whitelist: false,
+        max_per_user,
+        mint_limit: 0,
+        start_time: 0,
+        end_time: 0,
+        active: true,
+    }
+}
+
+fn buy(world: &mut ScenarioWorld, quantity: usize) {
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .egld(PRICE * quantity as u64)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.buy(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+                quantity,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        });
+}
+
+fn refund(world: &mut ScenarioWorld, nonce: u64) {
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .single_esdt(&TICKET_TOKEN.into(), nonce, &BigUint::from(1u32))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+}
+
+fn check_ledgers(world: &mut ScenarioWorld, platform_fees: u64, income: u64) {
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(
+                sc.platform_fees().get(&egld).unwrap_or_default(),
+                BigUint::from(platform_fees)
+            );
+            assert_eq!(
+                sc.income()
+                    .get(&egld)
+                    .map(|payment| payment.amount)
+                    .unwrap_or_default(),
+                BigUint::from(income)
+            );
+        });
+}
+
+fn check_escrow(world: &mut ScenarioWorld, platform_fees: u64, income: u64) {
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let (escrow_fees, escrow_income) = sc
+                .refund_escrow(&ManagedBuffer::from(EVENT_ID))
+                .get(&EgldOrEsdtTokenIdentifier::egld())
+                .map(|escrow| (escrow.platform_fees, escrow.income))
+                .unwrap_or_default();
+            assert_eq!(escrow_fees, BigUint::from(platform_fees));
+            assert_eq!(escrow_income, BigUint::from(income));
+        });
+}
+
+fn start_event(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.set_event_status(&ManagedBuffer::from(EVENT_ID), EventStatus::Live);
+        });
+}
+
+fn release_escrow(world: &mut ScenarioWorld, batch_size: usize) -> bool {
+    let mut done = false;
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            done = sc.release_refund_escrow_endpoint(&ManagedBuffer::from(EVENT_ID), batch_size);
+        });
+    done
+}
+
+fn check_ticket_held(world: &mut ScenarioWorld, nonce: u64) {
+    world.check_account(BUYER).esdt_nft_balance_and_attributes(
+        TICKET_TOKEN,
+        nonce,
+        1,
+        Attributes::<StaticApi> {
+            is_check_in: false,
+            event_id: ManagedBuffer::from(EVENT_ID),
+            ticket_type_id: ManagedBuffer::from(TICKET_TYPE_ID),
+            name: ManagedBuffer::from(format!("General #{nonce}").as_str()),
+        },
+    );
+}
+
+#[test]
+fn buy_splits_revenue_per_ticket() {
+    let mut world = setup(false);
+    buy(&mut world, 3);
+
+    // 5% of 1019 is 50.95, rounded down per ticket
+    check_ledgers(&mut world, 150, 2_907);
+    world
+        .check_account(TICKETING)
+        .balance(PRICE * 3)
+        .check_account(BUYER)
+        .balance(BUYER_BALANCE - PRICE * 3);
+    for nonce in 1..=3 {
+        check_ticket_held(&mut world, nonce);
+    }
+}
+
+#[test]
+fn refundable_sales_wait_in_escrow() {
+    let mut world = setup(true);
+    buy(&mut world, 3);
+
+    check_ledgers(&mut world, 0, 0);
+    check_escrow(&mut world, 150, 2_907);
+}
+
+#[test]
+fn refund_reverses_the_escrow() {
+    let mut world = setup(true);
+    buy(&mut world, 3);
+    refund(&mut world, 2);
+
+    check_ledgers(&mut world, 0, 0);
+    check_escrow(&mut world, 100, 1_938);
+    world
+        .check_account(BUYER)
+        .balance(BUYER_BALANCE - PRICE * 2);
+    check_ticket_held(&mut world, 1);
+    check_ticket_held(&mut world, 3);
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            assert_eq!(sc.event_by_id(&event_id).get().mint_count, 2);
+            assert!(sc.ticket_record(&event_id, 2).is_empty());
+        });
+}
+
+#[test]
+fn refund_after_flush_returns_the_full_price() {
+    let mut world = setup(true);
+    buy(&mut world, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.flush_platform_fees(OptionalValue::None);
+        });
+    world.check_account(FEE_RECIPIENT).balance(0);
+
+    refund(&mut world, 1);
+
+    check_escrow(&mut world, 50, 969);
+    world.check_account(BUYER).balance(BUYER_BALANCE - PRICE);
+}
+
+#[test]
+fn refund_after_claim_income_returns_the_full_price() {
+    let mut world = setup(true);
+    buy(&mut world, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.claim_income(&OWNER.to_managed_address());
+        });
+    world.check_account(OWNER).balance(0);
+
+    refund(&mut world, 1);
+
+    check_escrow(&mut world, 50, 969);
+    world.check_account(BUYER).balance(BUYER_BALANCE - PRICE);
+}
+
+#[test]
+fn escrow_is_released_once_the_event_starts() {
+    let mut world = setup(true);
+    buy(&mut world, 2);
+
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .returns(ExpectMessage("The refund window is still open!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.release_refund_escrow_endpoint(&ManagedBuffer::from(EVENT_ID), 10);
+        });
+
+    start_event(&mut world);
+    assert!(release_escrow(&mut world, 10));
+
+    check_ledgers(&mut world, 100, 1_938);
+    check_escrow(&mut world, 0, 0);
+
+    // Sales after the release go straight to the ledgers and cannot be refunded
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.set_event_status(&ManagedBuffer::from(EVENT_ID), EventStatus::Cancelled);
+        });
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .single_esdt(&TICKET_TOKEN.into(), 1, &BigUint::from(1u32))
+        .returns(ExpectMessage("The refund window is closed!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+}
+
+#[test]
+fn refund_requires_a_refund_policy() {
+    let mut world = setup(false);
+    buy(&mut world, 1);
+
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .single_esdt(&TICKET_TOKEN.into(), 1, &BigUint::from(1u32))
+        .returns(ExpectError(4, "The event does not allow refunds!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+    check_ledgers(&mut world, 50, 969);
+}
+
+#[test]
+fn refund_and_rebuy_counts_one_buyer() {
+    let mut world = setup(true);
+    buy(&mut world, 1);
+    refund(&mut world, 1);
+    buy(&mut world, 1);
+
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let stats = sc.get_stage_stats(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+            );
+            assert_eq!(stats.sold, 2);
+            assert_eq!(stats.refunds, 1);
+            assert_eq!(stats.unique_buyers, 1);
+        });
+}
+
+fn nested(message: &mut Vec<u8>, bytes: &[u8]) {
+    message.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    message.extend_from_slice(bytes);
+}
+
+fn sign_receipt(contract: &Address, order_id: &str, amount: u64, currency: &str) -> Vec<u8> {
+    let mut message = Vec::new();
+    nested(&mut message, b"ticketing_receipt");
+    message.extend_from_slice(contract.as_bytes());
+    nested(&mut message, order_id.as_bytes());
+    nested(
+        &mut message,
+        &num_bigint::BigUint::from(amount).to_bytes_be(),
+    );
+    nested(&mut message, currency.as_bytes());
+    message.extend_from_slice(BUYER.to_address().as_bytes());
+    nested(&mut message, EVENT_ID.as_bytes());
+    nested(&mut message, TICKET_TYPE_ID.as_bytes());
+    nested(&mut message, TICKET_STAGE_ID.as_bytes());
+    message.extend_from_slice(&2u32.to_be_bytes());
+
+    SigningKey::from_bytes(&PROVIDER_KEY)
+        .sign(&message)
+        .to_bytes()
+        .to_vec()
+}
+
+fn provider() -> Address {
+    Address::from(
+        SigningKey::from_bytes(&PROVIDER_KEY)
+            .verifying_key()
+            .to_bytes(),
+    )
+}
+
+fn setup_with_provider() -> ScenarioWorld {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.add_payment_provider(ManagedAddress::from(provider()));
+        });
+    world
+}
+
+fn buy_with_receipt(
+    world: &mut ScenarioWorld,
+    order_id: &str,
+    currency: &str,
+    signature: &[u8],
+    expect: Option<&str>,
+) {
+    let tx = world.tx().from(OWNER).to(TICKETING);
+    let call = |sc: ticketing::ContractObj<DebugApi>| {
+        sc.buy_with_receipt(
+            &ManagedBuffer::from(EVENT_ID),
+            &ManagedBuffer::from(TICKET_TYPE_ID),
+            &ManagedBuffer::from(TICKET_STAGE_ID),
+            2,
+            &BUYER.to_managed_address(),
+            &ManagedBuffer::from(order_id),
+            BigUint::from(2_500u64),
+            ManagedBuffer::from(currency),
+            ManagedAddress::from(provider()),
+            ManagedBuffer::from(signature),
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+    };
+    match expect {
+        Some(message) => tx
+            .returns(ExpectMessage(message))
+            .whitebox(ticketing::contract_obj, call),
+        None => tx.whitebox(ticketing::contract_obj, call),
+    }
+}
+
+#[test]
+fn receipt_mints_the_signed_order() {
+    let mut world = setup_with_provider();
+    let signature = sign_receipt(&TICKETING.to_address(), "order-1", 2_500, "EUR");
+    buy_with_receipt(&mut world, "order-1", "EUR", &signature, None);
+
+    check_ticket_held(&mut world, 1);
+    check_ticket_held(&mut world, 2);
+}
+
+#[test]
+fn receipt_fields_cannot_shift() {
+    let mut world = setup_with_provider();
+    let signature = sign_receipt(&TICKETING.to_address(), "order-1", 2_500, "EUR");
+    buy_with_receipt(
+        &mut world,
+        "order-1E",
+        "UR",
+        &signature,
+        Some("invalid signature"),
+    );
+}
+
+#[test]
+fn receipt_is_scoped_to_the_contract() {
+    let mut world = setup_with_provider();
+    let signature = sign_receipt(&OWNER.to_address(), "order-1", 2_500, "EUR");
+    buy_with_receipt(
+        &mut world,
+        "order-1",
+        "EUR",
+        &signature,
+        Some("invalid signature"),
+    );
+}
+
+fn setup_with_referral(refund_policy: bool) -> ScenarioWorld {
+    let mut world = setup(refund_policy);
+    world.account(AFFILIATE).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.set_max_referral_commission(MAX_COMMISSION);
+            sc.register_referral_code(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(REFERRAL_CODE),
+                AFFILIATE.to_managed_address(),
+                COMMISSION,
+            );
+        });
+    world
+}
+
+fn buy_with_referral(world: &mut ScenarioWorld, quantity: usize) {
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .egld(PRICE * quantity as u64)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.buy_with_referral(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+                quantity,
+                ManagedBuffer::from(REFERRAL_CODE),
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        });
+}
+
+fn check_escrowed_commission(world: &mut ScenarioWorld, commission: u64) {
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            assert_eq!(
+                sc.escrowed_commissions(
+                    &ManagedBuffer::from(EVENT_ID),
+                    &AFFILIATE.to_managed_address()
+                )
+                .get(&EgldOrEsdtTokenIdentifier::egld())
+                .unwrap_or_default(),
+                BigUint::from(commission)
+            );
+        });
+}
+
+fn check_referral_counters(world: &mut ScenarioWorld, orders: u32, tickets: u32) {
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let referral = sc
+                .referral_code(
+                    &ManagedBuffer::from(EVENT_ID),
+                    &ManagedBuffer::from(REFERRAL_CODE),
+                )
+                .get();
+            assert_eq!(referral.orders, orders);
+            assert_eq!(referral.tickets, tickets);
+        });
+}
+
+fn check_affiliate_balance(world: &mut ScenarioWorld, balance: u64) {
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            assert_eq!(
+                sc.affiliate_balance(&AFFILIATE.to_managed_address())
+                    .get(&EgldOrEsdtTokenIdentifier::egld())
+                    .unwrap_or_default(),
+                BigUint::from(balance)
+            );
+        });
+}
+
+#[test]
+fn referral_commission_comes_from_the_organizer_share() {
+    let mut world = setup_with_referral(false);
+    buy_with_referral(&mut world, 3);
+
+    // Per ticket: 50 platform, 10% of the remaining 969 rounded down to 96, 873 organizer
+    check_ledgers(&mut world, 150, 2_619);
+    check_affiliate_balance(&mut world, 288);
+    check_referral_counters(&mut world, 1, 3);
+}
+
+#[test]
+fn refund_claws_back_the_commission() {
+    let mut world = setup_with_referral(true);
+    buy_with_referral(&mut world, 3);
+    refund(&mut world, 3);
+
+    check_escrow(&mut world, 100, 1_746);
+    check_escrowed_commission(&mut world, 192);
+    check_affiliate_balance(&mut world, 0);
+    check_referral_counters(&mut world, 1, 2);
+    world
+        .check_account(BUYER)
+        .balance(BUYER_BALANCE - PRICE * 2);
+}
+
+#[test]
+fn refunding_a_whole_order_drops_it_from_the_referral() {
+    let mut world = setup_with_referral(true);
+    buy_with_referral(&mut world, 1);
+    buy_with_referral(&mut world, 2);
+    refund(&mut world, 2);
+    check_referral_counters(&mut world, 2, 2);
+    refund(&mut world, 3);
+    check_referral_counters(&mut world, 1, 1);
+}
+
+#[test]
+fn referral_commission_is_capped() {
+    let mut world = setup_with_referral(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .returns(ExpectMessage(
+            "The commission is above the allowed maximum!",
+        ))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.register_referral_code(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from("greedy"),
+                AFFILIATE.to_managed_address(),
+                MAX_COMMISSION + 1,
+            );
+        });
+}
+
+#[test]
+fn refund_after_affiliate_claim_returns_the_full_price() {
+    let mut world = setup_with_referral(true);
+    buy_with_referral(&mut world, 2);
+    world
+        .tx()
+        .from(AFFILIATE)
+        .to(TICKETING)
+        .returns(ExpectMessage("There are no earnings to claim!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.claim_affiliate_earnings();
+        });
+
+    refund(&mut world, 1);
+
+    check_escrowed_commission(&mut world, 96);
+    world.check_account(BUYER).balance(BUYER_BALANCE - PRICE);
+}
+
+#[test]
+fn released_commission_can_be_claimed() {
+    let mut world = setup_with_referral(true);
+    buy_with_referral(&mut world, 2);
+    start_event(&mut world);
+
+    // One unit per affiliate, then one for the event escrow
+    assert!(!release_escrow(&mut world, 1));
+    check_affiliate_balance(&mut world, 192);
+    check_ledgers(&mut world, 0, 0);
+    assert!(release_escrow(&mut world, 1));
+    check_ledgers(&mut world, 100, 1_746);
+
+    world
+        .tx()
+        .from(AFFILIATE)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.claim_affiliate_earnings();
+        });
+    world.check_account(AFFILIATE).balance(192);
+    check_escrowed_commission(&mut world, 0);
+}
+
+#[test]
+fn own_referral_code_is_rejected() {
+    let mut world = setup_with_referral(false);
+    world
+        .tx()
+        .from(AFFILIATE)
+        .to(TICKETING)
+        .returns(ExpectMessage("You cannot use your own referral code!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.buy_with_referral(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+                1,
+                ManagedBuffer::from(REFERRAL_CODE),
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        });
+}
+
+fn cancel_event(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.set_event_status(&ManagedBuffer::from(EVENT_ID), EventStatus::Cancelled);
+        });
+}
+
+fn archive(world: &mut ScenarioWorld, batch_size: usize) -> bool {
+    let mut done = false;
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            done = sc.archive_event(&ManagedBuffer::from(EVENT_ID), batch_size);
+        });
+    done
+}
+
+#[test]
+fn archive_forgets_the_collection() {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.collections().insert(TICKET_TOKEN.to_token_identifier());
+            sc.metadata_update_state(&ManagedBuffer::from(EVENT_ID))
+                .set(MetadataUpdateState::Enabled);
+        });
+    buy(&mut world, 1);
+    cancel_event(&mut world);
+    assert!(archive(&mut world, 100));
+
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            assert!(sc.collections().is_empty());
+            assert!(sc.metadata_update_state(&event_id).is_empty());
+            assert!(sc.archived_events().contains(&event_id));
+        });
+}
+
+#[test]
+fn whitelist_requires_an_existing_stage() {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .returns(ExpectMessage("Your ticket stage ID: vip is not valid!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.add_to_whitelist(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from("vip"),
+                MultiValueEncoded::from(ManagedVec::from_single_item(BUYER.to_managed_address())),
+            );
+        });
+}
+
+#[test]
+fn ticket_type_max_per_user_covers_its_stages() {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_ticket_stage(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                ticket_stage_args(5),
+            );
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .returns(ExpectMessage(
+            "The ticket stage presale max per user exceeds the ticket type max per user!",
+        ))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_ticket_type(&ManagedBuffer::from(EVENT_ID), ticket_type_args(3));
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_ticket_type(&ManagedBuffer::from(EVENT_ID), ticket_type_args(5));
+        });
+}
+
+#[test]
+fn event_max_per_user_covers_its_ticket_types() {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_ticket_type(&ManagedBuffer::from(EVENT_ID), ticket_type_args(4));
+        });
+
+    let event_args = |max_per_user| EventArgs {
+        max_capacity: 0,
+        max_per_user,
+        has_kyc: false,
+        refund_policy: false,
+        append_number: true,
+        bot_protection: false,
+    };
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .returns(ExpectMessage(
+            "The ticket type general max per user exceeds the event max per user!",
+        ))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_event(&ManagedBuffer::from(EVENT_ID), event_args(3));
+        });
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.edit_event(&ManagedBuffer::from(EVENT_ID), event_args(4));
+        });
+}
+
+#[test]
+fn unfiltered_stage_pages_skip_by_index() {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let mut args = ticket_type_args(0);
+            args.id = ManagedBuffer::from("backstage");
+            sc.insert_ticket_type(&event_id, &args);
+            for stage_id in ["early", "late"] {
+                let mut args = ticket_stage_args(0);
+                args.id = ManagedBuffer::from(stage_id);
+                sc.insert_ticket_stage(&event_id, &ManagedBuffer::from("backstage"), &args);
+            }
+        });
+
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let ids = |page: ManagedVec<DebugApi, _>| {
+                page.iter()
+                    .map(|stage: TicketStage<DebugApi>| stage.id.to_boxed_bytes().into_vec())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                ids(sc.get_all_stages_page(&event_id, 1, 2, false)),
+                vec![b"early".to_vec(), b"late".to_vec()]
+            );
+            assert_eq!(
+                ids(sc.get_all_stages_page(&event_id, 2, 5, false)),
+                vec![b"late".to_vec()]
+            );
+            assert!(sc.get_all_stages_page(&event_id, 3, 5, false).is_empty());
+
+            assert_eq!(sc.get_events_page(0, 5, OptionalValue::None).len(), 1);
+            assert!(sc.get_events_page(1, 5, OptionalValue::None).is_empty());
+        });
+}
+
+fn check_in(world: &mut ScenarioWorld, nonce: u64, expect: Option<&str>) {
+    let tx = world.tx().from(OWNER).to(TICKETING);
+    let call = |sc: ticketing::ContractObj<DebugApi>| {
+        sc.check_in_tickets(
+            &ManagedBuffer::from(EVENT_ID),
+            MultiValueEncoded::from(ManagedVec::from_single_item(nonce)),
+        );
+    };
+    match expect {
+        Some(message) => tx
+            .returns(ExpectMessage(message))
+            .whitebox(ticketing::contract_obj, call),
+        None => tx.whitebox(ticketing::contract_obj, call),
+    }
+}
+
+#[test]
+fn refunded_tickets_cannot_be_checked_in() {
+    let mut world = setup(true);
+    buy(&mut world, 2);
+    refund(&mut world, 2);
+
+    check_in(&mut world, 1, None);
+    check_in(
+        &mut world,
+        2,
+        Some("The ticket 2 does not belong to this event!"),
+    );
+}
+
+#[test]
+fn refund_takes_only_tickets() {
+    let mut world = setup(true);
+    buy(&mut world, 1);
+
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .egld(PRICE)
+        .returns(ExpectMessage("Only tickets can be sent for a refund!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .returns(ExpectMessage("No tickets were sent for a refund!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+}
+
+fn setup_with_aggregator(
+    outputs: &[(TestTokenIdentifier, u64)],
+    egld_output: u64,
+) -> ScenarioWorld {
+    let mut world = setup(false);
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(MOCK_AGGREGATOR_PATH)
+        .new_address(MOCK_AGGREGATOR)
+        .whitebox(mock_aggregator::contract_obj, |sc| {
+            sc.init();
+        });
+    world.set_egld_balance(MOCK_AGGREGATOR, egld_output);
+    world.set_esdt_balance(BUYER, INPUT_TOKEN.as_bytes(), 5_000u64);
+
+    let mut totals: Vec<(TestTokenIdentifier, u64)> = Vec::new();
+    for (token, amount) in outputs {
+        match totals.iter_mut().find(|(total, _)| total == token) {
+            Some((_, total)) => *total += amount,
+            None => totals.push((*token, *amount)),
+        }
+    }
+    for (token, amount) in &totals {
+        world.set_esdt_balance(MOCK_AGGREGATOR, token.as_bytes(), *amount);
+    }
+
+    let outputs = outputs.to_vec();
+    world
+        .tx()
+        .from(OWNER)
+        .to(MOCK_AGGREGATOR)
+        .whitebox(mock_aggregator::contract_obj, |sc| {
+            for (token, amount) in &outputs {
+                sc.esdt_outputs().push(&EsdtTokenPayment::new(
+                    token.to_token_identifier(),
+                    0,
+                    BigUint::from(*amount),
+                ));
+            }
+            sc.egld_output().set(BigUint::from(egld_output));
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.aggregator().set(MOCK_AGGREGATOR.to_managed_address());
+            let mut args = ticket_stage_args(0);
+            args.prices.push(StagePrice {
+                token_identifier: EgldOrEsdtTokenIdentifier::esdt(
+                    WEGLD_TOKEN.to_token_identifier(),
+                ),
+                token_nonce: 0,
+                amount: BigUint::from(PRICE),
+            });
+            sc.edit_ticket_stage(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                args,
+            );
+        });
+
+    world
+}
+
+fn buy_with_swap(world: &mut ScenarioWorld, quantity: usize, expect: Option<&str>) {
+    let input_token = INPUT_TOKEN.to_token_identifier();
+    let input_amount = BigUint::from(5_000u64);
+    let tx = world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .single_esdt(&input_token, 0, &input_amount);
+    let call = |sc: ticketing::ContractObj<DebugApi>| {
+        sc.buy(
+            &ManagedBuffer::from(EVENT_ID),
+            &ManagedBuffer::from(TICKET_TYPE_ID),
+            &ManagedBuffer::from(TICKET_STAGE_ID),
+            quantity,
+            OptionalValue::None,
+            OptionalValue::None,
+            OptionalValue::Some(ManagedVec::new()),
+            OptionalValue::Some(ManagedVec::new()),
+        );
+    };
+    match expect {
+        Some(message) => tx
+            .returns(ExpectMessage(message))
+            .whitebox(ticketing::contract_obj, call),
+        None => tx.whitebox(ticketing::contract_obj, call),
+    }
+}
+
+#[test]
+fn swap_outputs_of_one_token_are_summed() {
+    let mut world = setup_with_aggregator(
+        &[(WEGLD_TOKEN, 1_100), (MEX_TOKEN, 70), (WEGLD_TOKEN, 1_100)],
+        0,
+    );
+    buy_with_swap(&mut world, 2, None);
+
+    check_ticket_held(&mut world, 1);
+    check_ticket_held(&mut world, 2);
+    world
+        .check_account(BUYER)
+        .esdt_balance(WEGLD_TOKEN, 2_200 - PRICE * 2)
+        .esdt_balance(MEX_TOKEN, 70)
+        .esdt_balance(INPUT_TOKEN, 0);
+    world
+        .check_account(TICKETING)
+        .esdt_balance(WEGLD_TOKEN, PRICE * 2);
+}
+
+#[test]
+fn swap_surplus_is_refunded_in_egld_and_esdt() {
+    let mut world = setup_with_aggregator(&[(MEX_TOKEN, 70)], 1_500);
+    buy_with_swap(&mut world, 1, None);
+
+    check_ticket_held(&mut world, 1);
+    check_ledgers(&mut world, 50, 969);
+    world
+        .check_account(BUYER)
+        .balance(BUYER_BALANCE + 1_500 - PRICE)
+        .esdt_balance(MEX_TOKEN, 70);
+    world.check_account(TICKETING).balance(PRICE);
+}
+
+#[test]
+fn swap_without_a_covering_output_is_rejected() {
+    let mut world = setup_with_aggregator(&[(WEGLD_TOKEN, 1_000), (MEX_TOKEN, 5_000)], PRICE - 1);
+    buy_with_swap(
+        &mut world,
+        1,
+        Some("The payment amount is under the total value required for the buy!"),
+    );
+
+    world
+        .check_account(BUYER)
+        .balance(BUYER_BALANCE)
+        .esdt_balance(INPUT_TOKEN, 5_000);
+}
+
+#[test]
+fn stage_cleanup_resumes_across_calls() {
+    let mut world = setup(false);
+    buy(&mut world, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let type_id = ManagedBuffer::from(TICKET_TYPE_ID);
+            let stage_id = ManagedBuffer::from(TICKET_STAGE_ID);
+            sc.add_to_whitelist(
+                &event_id,
+                &type_id,
+                &stage_id,
+                MultiValueEncoded::from(ManagedVec::from(vec![
+                    BUYER.to_managed_address(),
+                    AFFILIATE.to_managed_address(),
+                ])),
+            );
+            sc.remove_ticket_stage(&event_id, &type_id, &stage_id);
+        });
+
+    // Two whitelisted wallets, one buyer, then the stage analytics
+    let mut calls = 0;
+    loop {
+        calls += 1;
+        let mut done = false;
+        world
+            .tx()
+            .from(OWNER)
+            .to(TICKETING)
+            .whitebox(ticketing::contract_obj, |sc| {
+                done = sc.clean_up_ticket_data_endpoint(&ManagedBuffer::from(EVENT_ID), 1);
+            });
+        if done {
+            break;
+        }
+    }
+    assert_eq!(calls, 4);
+
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let type_id = ManagedBuffer::from(TICKET_TYPE_ID);
+            let stage_id = ManagedBuffer::from(TICKET_STAGE_ID);
+            assert!(sc.pending_cleanups(&event_id).is_empty());
+            assert!(sc.cleanup_cursor(&event_id).is_empty());
+            assert!(sc
+                .whitelist_wallets(&event_id, &type_id, &stage_id)
+                .is_empty());
+            assert!(sc
+                .buys_per_ticket_stage(&BUYER.to_managed_address(), &event_id, &type_id, &stage_id)
+                .is_empty());
+            assert!(sc
+                .stage_buyer(&BUYER.to_managed_address(), &event_id, &type_id, &stage_id)
+                .is_empty());
+        });
+}
+
+#[test]
+fn archive_resumes_across_calls() {
+    let mut world = setup(true);
+    buy(&mut world, 3);
+    check_in(&mut world, 1, None);
+    cancel_event(&mut world);
+
+    let mut calls = 1;
+    while !archive(&mut world, 2) {
+        calls += 1;
+        assert!(calls < 20);
+    }
+    assert!(calls > 2);
+
+    // Cancelled before any refund, the escrow is released when the event is archived
+    check_ledgers(&mut world, 150, 2_907);
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            assert!(sc.archived_events().contains(&event_id));
+            assert!(sc.event_by_id(&event_id).is_empty());
+            assert!(sc.event_buyers(&event_id).is_empty());
+            assert!(sc.ticket_types(&event_id).is_empty());
+            assert!(sc.checked_in_tickets(&event_id).is_empty());
+            assert!(sc.archive_cursor(&event_id).is_empty());
+            assert!(sc.refund_escrow(&event_id).is_empty());
+            for nonce in 1..=3 {
+                assert!(sc.ticket_record(&event_id, nonce).is_empty());
+            }
+        });
+}
+
+// Whitebox calls run on the default gas, the airdrop budget needs a real call
+fn continue_airdrop(world: &mut ScenarioWorld, gas: u64) -> (u32, u32, u32) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .gas(gas)
+        .raw_call("continueAirdrop")
+        .argument(&EVENT_ID)
+        .argument(&"drop")
+        .run();
+
+    let mut progress = (0, 0, 0);
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let job = sc
+                .airdrop_job(&ManagedBuffer::from(EVENT_ID), &ManagedBuffer::from("drop"))
+                .get();
+            progress = (job.processed, job.minted, job.failed);
+        });
+    progress
+}
+
+#[test]
+fn airdrop_resumes_and_records_failures() {
+    let mut world = setup(false);
+    world.account(OPERATOR).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let event_id = ManagedBuffer::from(EVENT_ID);
+            let job_id = ManagedBuffer::from("drop");
+            sc.create_airdrop(
+                &event_id,
+                &job_id,
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+                false,
+            );
+            let mut recipients = MultiValueEncoded::new();
+            recipients.push((BUYER.to_managed_address(), 1u32).into());
+            recipients.push((TICKETING.to_managed_address(), 1u32).into());
+            recipients.push((OPERATOR.to_managed_address(), 10u32).into());
+            sc.add_airdrop_recipients(&event_id, &job_id, recipients);
+        });
+
+    // 5M per recipient plus 3M per ticket, the third recipient needs 35M
+    assert_eq!(continue_airdrop(&mut world, 20_000_000), (2, 1, 1));
+    assert_eq!(continue_airdrop(&mut world, 50_000_000), (3, 11, 1));
+    check_ticket_held(&mut world, 1);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .returns(ExpectMessage("The airdrop is already completed!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.continue_airdrop(&ManagedBuffer::from(EVENT_ID), &ManagedBuffer::from("drop"));
+        });
+    world
+        .query()
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            let failures =
+                sc.airdrop_failures(&ManagedBuffer::from(EVENT_ID), &ManagedBuffer::from("drop"));
+            assert_eq!(failures.len(), 1);
+            let failure = failures.get(1);
+            assert_eq!(failure.address, TICKETING.to_managed_address());
+            assert_eq!(
+                failure.reason,
+                ManagedBuffer::from("Only user accounts are allowed to mint")
+            );
+        });
+}
+
+#[test]
+fn pause_blocks_sales_and_refunds() {
+    let mut world = setup(true);
+    buy(&mut world, 1);
+
+    // The owner check sits in the endpoint wrapper, which a whitebox call skips
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .raw_call("pause")
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.pause();
+        });
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .egld(PRICE)
+        .returns(ExpectMessage("The contract is paused!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.buy(
+                &ManagedBuffer::from(EVENT_ID),
+                &ManagedBuffer::from(TICKET_TYPE_ID),
+                &ManagedBuffer::from(TICKET_STAGE_ID),
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.unpause();
+            sc.pause_event(&ManagedBuffer::from(EVENT_ID));
+        });
+    world
+        .tx()
+        .from(BUYER)
+        .to(TICKETING)
+        .single_esdt(&TICKET_TOKEN.into(), 1, &BigUint::from(1u32))
+        .returns(ExpectMessage("The event is paused!"))
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.refund(&ManagedBuffer::from(EVENT_ID));
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(TICKETING)
+        .whitebox(ticketing::contract_obj, |sc| {
+            sc.unpause_event(&ManagedBuffer::from(EVENT_ID));
+        });
+    buy(&mut world, 1);
+    refund(&mut world, 1);
+}
+
+fn grant_role(
+    world: &mut ScenarioWorld,
+    from: TestAddress,
+    role: Role,
+    to: TestAddress,
+    expect: Option<&str>,
+) {
+    let tx = world.tx().from(from).to(TICKETING);
+    let call = |sc: ticketing::ContractObj<DebugApi>| {
+        let event_id = match role {
+            Role::Admin => OptionalValue::None,
+            _ => OptionalValue::Some(ManagedBuffer::from(EVENT_ID)),
+        };
+        sc.grant_role(role, to.to_managed_address(), event_id);
+    };
+    match expect {
+        Some(message) => tx
+            .returns(ExpectMessage(message))
+            .whitebox(ticketing::contract_obj, call),
+        None => tx.whitebox(ticketing::contract_obj, call),
+    }
+}
+
+#[test]
+fn roles_cannot_grant_above_themselves() {
+    let mut world = setup(false);
+    world.account(ORGANIZER).nonce(1);
+    world.account(OPERATOR).nonce(1);
+    world.account(AFFILIATE).nonce(1);
+
+    grant_role(&mut world, OWNER, Role::Organizer, ORGANIZER, None);
+    grant_role(&mut world, ORGANIZER, Role::Operator, OPERATOR, None);
+    grant_role(
+        &mut world,
+        ORGANIZER,
+        Role::Organizer,
+        OPERATOR,
+        Some("Only admins can perform this action!"),
+    );
+    grant_role(
+        &mut world,
+        ORGANIZER,
+        Role::Admin,
+        ORGANIZER,
+        Some("Only the owner can perform this action!"),
+    );
+    grant_role(
+        &mut world,
+        OPERATOR,
+        Role::Scanner,
+        OPERATOR,
+        Some("Only the event organizers can perform this action!"),
+    );
+
+    // Admins appoint organizers but only the owner appoints admins
+    grant_role(&mut world, OWNER, Role::Admin, AFFILIATE, None);
+    grant_role(&mut world, AFFILIATE, Role::Organizer, OPERATOR, None);
+    grant_role(
+        &mut world,
+        AFFILIATE,
+        Role::Admin,
+        OPERATOR,
+        Some("Only the owner can perform this action!"),
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           39
+// Endpoints:                          117
 // Async Callback:                       1
-// Total number of exported functions:  42
+// Total number of exported functions: 120
 
 #![no_std]
 
@@ -21,10 +21,22 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         buyTicket => buy
+        buyTicketWithReferral => buy_with_referral
+        buyWithReceipt => buy_with_receipt
+        refundTicket => refund
         issueFreeTicket => giveaway
+        issueFreeStageTicket => giveaway_stage
         issuePaidTicket => giveaway_admin
         tradingControl => trading_control
+        enableMetadataUpdates => enable_metadata_updates
+        flushPlatformFees => flush_platform_fees
+        releaseRefundEscrow => release_refund_escrow_endpoint
+        refreshManagerConfig => refresh_manager_config
+        addPaymentProvider => add_payment_provider
+        removePaymentProvider => remove_payment_provider
+        setFeeRecipient => set_fee_recipient
         setFees => set_cut_fees
+        setEventFees => set_event_fees
         getAllEvents => events
         getEvent => event_by_id
         getAllTicketTypes => ticket_types
@@ -37,28 +49,94 @@ multiversx_sc_wasm_adapter::endpoints! {
         getNonce => next_nonce
         collections => collections
         getTokenByEventId => token_manager
+        getTicketTypeNonces => ticket_type_nonces
+        getCompedTickets => comped_tickets
+        getMetadataCursor => metadata_cursor
+        getMetadataUpdateState => metadata_update_state
         getTransferWallets => transfer_wallets
+        getEventBuyers => event_buyers
+        getTicketRecord => ticket_record
+        getCheckedInTickets => checked_in_tickets
+        getExternalOrders => external_orders
+        getExternalOrderTickets => external_order_tickets
+        getRefundEscrow => refund_escrow
+        getEscrowedAffiliates => escrowed_affiliates
+        getEscrowedCommissions => escrowed_commissions
+        isRefundWindowClosed => refund_window_closed
+        getArchivedEvents => archived_events
+        getEventSummary => event_summary
+        getPendingCleanups => pending_cleanups
+        getReferralCodes => referral_codes
+        getReferralCode => referral_code
+        getAffiliateBalance => affiliate_balance
+        getMaxReferralCommission => max_referral_commission
+        getAirdropJobs => airdrop_jobs
+        getAirdropProgress => airdrop_job
+        getAirdropFailures => airdrop_failures
+        getAdmins => admins
+        getEventRoleMembers => event_role_members
+        isPaused => paused
+        isEventPaused => event_paused
         getFees => fees
         getIncome => income
+        getPlatformFees => platform_fees
+        getSigner => signer
+        getAggregator => aggregator
+        getPaymentProviders => payment_providers
+        isStandalone => standalone
+        getFeeRecipient => fee_recipient
         isWhitelisted => is_whitelisted
+        getEventSchemaVersion => get_event_schema_version
         getEvents => get_events
+        getEventSchedule => get_event_schedule_view
         getTypes => get_types
         getTypeStages => get_type_stages
         getAllStages => get_all_stages
+        getEventSnapshot => get_event_snapshot
+        canBuy => can_buy
+        getQuote => get_quote
+        getStageReport => get_stage_report
+        getEventsPage => get_events_page
+        getTypesPage => get_types_page
+        getAllStagesPage => get_all_stages_page
+        getAllowedUsersPage => get_allowed_users_page
         getAllIncomeTokens => get_all_income_tokens
         getIncomePayment => get_all_income_payments
         whitelistSize => whitelisted_size
         createEvent => create_event
         createTicketType => create_ticket_type
         createTicketStage => create_ticket_stage
+        configureEvent => configure_event
         removeTicketType => remove_ticket_type
         removeTicketStage => remove_ticket_stage
+        cleanUpTicketData => clean_up_ticket_data_endpoint
+        addEventBuyers => add_event_buyers
         editTicketType => edit_ticket_type
+        refreshTicketTypeMetadata => refresh_ticket_type_metadata
+        addTicketTypeNonces => add_ticket_type_nonces
         editTicketStage => edit_ticket_stage
         editEvent => edit_event
+        setEventSchedule => set_event_schedule
+        setEventStatus => set_event_status
+        checkInTickets => check_in_tickets
         addWhitelists => add_to_whitelist
         removeWhitelists => remove_from_whitelist
         claimIncome => claim_income
+        pause => pause
+        unpause => unpause
+        pauseEvent => pause_event
+        unpauseEvent => unpause_event
+        grantRole => grant_role
+        revokeRole => revoke_role
+        archiveEvent => archive_event
+        createAirdrop => create_airdrop
+        addAirdropRecipients => add_airdrop_recipients
+        continueAirdrop => continue_airdrop
+        registerReferralCode => register_referral_code
+        removeReferralCode => remove_referral_code
+        setMaxReferralCommission => set_max_referral_commission
+        claimAffiliateEarnings => claim_affiliate_earnings
+        getAffiliateSales => get_affiliate_sales
     )
 }
 